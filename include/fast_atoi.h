@@ -0,0 +1,105 @@
+#ifndef FAST_ATOI_H
+#define FAST_ATOI_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+// The result of a `fast_atoi_<type>` function.
+typedef enum FastAtoiStatus {
+  // The input was parsed and the value written to `out`.
+  FAST_ATOI_STATUS_OK = 0,
+  // The input is not an integer, or `text` is null with a non-zero `len`.
+  FAST_ATOI_STATUS_INVALID = 1,
+  // The input is an integer, but does not fit in the target type.
+  FAST_ATOI_STATUS_OVERFLOW = 2,
+} FastAtoiStatus;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Parses `len` bytes at `text` as a `u8`, with the grammar of `fast_atoi::atoi`.
+//
+// # Safety
+//
+// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+// valid for writes.
+enum FastAtoiStatus fast_atoi_u8(const char *text, size_t len, uint8_t *out);
+
+// Parses `len` bytes at `text` as a `u16`, with the grammar of `fast_atoi::atoi`.
+//
+// # Safety
+//
+// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+// valid for writes.
+enum FastAtoiStatus fast_atoi_u16(const char *text, size_t len, uint16_t *out);
+
+// Parses `len` bytes at `text` as a `u32`, with the grammar of `fast_atoi::atoi`.
+//
+// # Safety
+//
+// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+// valid for writes.
+enum FastAtoiStatus fast_atoi_u32(const char *text, size_t len, uint32_t *out);
+
+// Parses `len` bytes at `text` as a `u64`, with the grammar of `fast_atoi::atoi`.
+//
+// # Safety
+//
+// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+// valid for writes.
+enum FastAtoiStatus fast_atoi_u64(const char *text, size_t len, uint64_t *out);
+
+// Parses `len` bytes at `text` as a `u128`, with the grammar of `fast_atoi::atoi`.
+//
+// # Safety
+//
+// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+// valid for writes.
+enum FastAtoiStatus fast_atoi_u128(const char *text, size_t len, unsigned __int128 *out);
+
+// Parses `len` bytes at `text` as an `i8`, with the grammar of `fast_atoi::atoi`.
+//
+// # Safety
+//
+// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+// valid for writes.
+enum FastAtoiStatus fast_atoi_i8(const char *text, size_t len, int8_t *out);
+
+// Parses `len` bytes at `text` as an `i16`, with the grammar of `fast_atoi::atoi`.
+//
+// # Safety
+//
+// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+// valid for writes.
+enum FastAtoiStatus fast_atoi_i16(const char *text, size_t len, int16_t *out);
+
+// Parses `len` bytes at `text` as an `i32`, with the grammar of `fast_atoi::atoi`.
+//
+// # Safety
+//
+// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+// valid for writes.
+enum FastAtoiStatus fast_atoi_i32(const char *text, size_t len, int32_t *out);
+
+// Parses `len` bytes at `text` as an `i64`, with the grammar of `fast_atoi::atoi`.
+//
+// # Safety
+//
+// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+// valid for writes.
+enum FastAtoiStatus fast_atoi_i64(const char *text, size_t len, int64_t *out);
+
+// Parses `len` bytes at `text` as an `i128`, with the grammar of `fast_atoi::atoi`.
+//
+// # Safety
+//
+// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+// valid for writes.
+enum FastAtoiStatus fast_atoi_i128(const char *text, size_t len, __int128 *out);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* FAST_ATOI_H */
@@ -0,0 +1,244 @@
+use crate::core::{error, parse_16, strip_leading_zeros};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// 10^16, the base of the chunks that `parse_16` produces.
+pub(crate) const CHUNK: u64 = 10_000_000_000_000_000;
+
+/// Strips the optional '+' and the leading zeros of an unsigned integer.
+#[inline]
+fn significant_digits(mut text: &[u8]) -> Result<&[u8], ()> {
+    if !text.is_empty() && text[0] == b'+' {
+        text = &text[1..];
+    }
+    if text.is_empty() {
+        return error::<&[u8]>();
+    }
+    strip_leading_zeros(&mut text, 0);
+    Ok(text)
+}
+
+/// Calls `f` with every 16 digit chunk of `s`, most significant first. The first chunk is shorter
+/// if the length is not a multiple of 16.
+#[inline]
+pub(crate) fn for_each_chunk(mut s: &[u8], mut f: impl FnMut(u64)) -> Result<(), ()> {
+    let mut err = 0;
+    let head = s.len() % 16;
+    if head != 0 {
+        let mut buf = [b'0'; 16];
+        buf[16 - head..].copy_from_slice(&s[..head]);
+        f(parse_16(&mut &buf[..], &mut err));
+        s = &s[head..];
+    }
+    while !s.is_empty() {
+        f(parse_16(&mut s, &mut err));
+    }
+    match err {
+        0 => Ok(()),
+        _ => error::<()>(),
+    }
+}
+
+/// Sets `limbs = limbs * mul + add`, returning the carry out of the last limb.
+#[inline]
+pub(crate) fn mul_add(limbs: &mut [u64], mul: u64, add: u64) -> u64 {
+    let mut carry = add;
+    for limb in limbs {
+        let t = *limb as u128 * mul as u128 + carry as u128;
+        *limb = t as u64;
+        carry = (t >> 64) as u64;
+    }
+    carry
+}
+
+/// Parses an unsigned integer of any length, `r"\+?[0-9]+$"`, into little-endian `u64` limbs in
+/// `limbs`, returning the number of limbs used. The value zero uses no limbs.
+///
+/// This does not allocate, and takes quadratic time in the number of digits. Each limb holds a
+/// little over 19 digits. If the value does not fit in `limbs`, it is an error.
+///
+/// ```
+/// use fast_atoi::parse_biguint_into;
+///
+/// let mut limbs = [0u64; 2];
+/// assert_eq!(parse_biguint_into(b"18446744073709551616", &mut limbs), Ok(2));
+/// assert_eq!(limbs, [0, 1]);
+/// assert!(parse_biguint_into(b"340282366920938463463374607431768211456", &mut limbs).is_err());
+/// ```
+#[inline]
+pub fn parse_biguint_into(text: &[u8], limbs: &mut [u64]) -> Result<usize, ()> {
+    let s = significant_digits(text)?;
+    let mut len = 0;
+    let mut overflow = false;
+    for_each_chunk(s, |chunk| {
+        let carry = mul_add(&mut limbs[..len], CHUNK, chunk);
+        if carry != 0 {
+            match limbs.get_mut(len) {
+                Some(limb) => {
+                    *limb = carry;
+                    len += 1;
+                }
+                // The high limbs are lost, but the digits are still validated.
+                None => overflow = true,
+            }
+        }
+    })?;
+    match overflow {
+        false => Ok(len),
+        true => error::<usize>(),
+    }
+}
+
+/// Below this many chunks, chunks are combined one at a time.
+#[cfg(feature = "alloc")]
+const CONVERT_THRESHOLD: usize = 32;
+
+/// Below this many limbs in the shorter factor, products are computed by long multiplication.
+#[cfg(feature = "alloc")]
+const KARATSUBA_THRESHOLD: usize = 32;
+
+#[cfg(feature = "alloc")]
+#[inline]
+fn trim(mut v: Vec<u64>) -> Vec<u64> {
+    while v.last() == Some(&0) {
+        v.pop();
+    }
+    v
+}
+
+/// Adds `x << (64 * shift)` to `acc`, which must be long enough for the sum.
+#[cfg(feature = "alloc")]
+#[inline]
+fn add_shifted(acc: &mut [u64], x: &[u64], shift: usize) {
+    let mut carry = false;
+    let mut i = shift;
+    for &limb in x {
+        let (sum, c1) = acc[i].overflowing_add(limb);
+        let (sum, c2) = sum.overflowing_add(carry as u64);
+        acc[i] = sum;
+        carry = c1 | c2;
+        i += 1;
+    }
+    while carry {
+        let (sum, c) = acc[i].overflowing_add(1);
+        acc[i] = sum;
+        carry = c;
+        i += 1;
+    }
+}
+
+/// Subtracts `x` from `acc`, which must not be smaller.
+#[cfg(feature = "alloc")]
+#[inline]
+fn sub_assign(acc: &mut [u64], x: &[u64]) {
+    let mut borrow = false;
+    for (i, a) in acc.iter_mut().enumerate() {
+        if i >= x.len() && !borrow {
+            break;
+        }
+        let (diff, b1) = a.overflowing_sub(x.get(i).copied().unwrap_or(0));
+        let (diff, b2) = diff.overflowing_sub(borrow as u64);
+        *a = diff;
+        borrow = b1 | b2;
+    }
+    debug_assert!(!borrow);
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+fn add(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut sum = alloc::vec![0; a.len().max(b.len()) + 1];
+    add_shifted(&mut sum, a, 0);
+    add_shifted(&mut sum, b, 0);
+    sum
+}
+
+/// Multiplies two numbers with Karatsuba's algorithm, `O(n^1.585)` in the number of limbs.
+#[cfg(feature = "alloc")]
+fn mul(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut product = alloc::vec![0; a.len() + b.len()];
+    if b.len() < KARATSUBA_THRESHOLD {
+        for (i, &limb) in b.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &x) in a.iter().enumerate() {
+                let t = x as u128 * limb as u128 + product[i + j] as u128 + carry as u128;
+                product[i + j] = t as u64;
+                carry = (t >> 64) as u64;
+            }
+            product[i + a.len()] = carry;
+        }
+        return product;
+    }
+
+    let m = a.len() / 2;
+    if b.len() <= m {
+        // Too unbalanced to split both: multiply `b` by each half of `a`.
+        add_shifted(&mut product, &trim(mul(&a[..m], b)), 0);
+        add_shifted(&mut product, &trim(mul(&a[m..], b)), m);
+        return product;
+    }
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m);
+    let z0 = trim(mul(a0, b0));
+    let z2 = trim(mul(a1, b1));
+    // (a0 + a1)(b0 + b1) - z0 - z2 = a0 b1 + a1 b0
+    let mut z1 = mul(&trim(add(a0, a1)), &trim(add(b0, b1)));
+    sub_assign(&mut z1, &z0);
+    sub_assign(&mut z1, &z2);
+    add_shifted(&mut product, &z0, 0);
+    add_shifted(&mut product, &trim(z1), m);
+    add_shifted(&mut product, &z2, 2 * m);
+    product
+}
+
+/// Combines chunks, most significant first, into limbs. `powers[j]` is `CHUNK^(2^j)`.
+#[cfg(feature = "alloc")]
+fn convert(chunks: &[u64], powers: &[Vec<u64>]) -> Vec<u64> {
+    if chunks.len() <= CONVERT_THRESHOLD {
+        let mut limbs = Vec::with_capacity(chunks.len());
+        for &chunk in chunks {
+            let carry = mul_add(&mut limbs, CHUNK, chunk);
+            if carry != 0 {
+                limbs.push(carry);
+            }
+        }
+        return limbs;
+    }
+    // The low half is the largest power of two chunks, so its scale is in `powers`.
+    let j = (usize::BITS - 1 - (chunks.len() - 1).leading_zeros()) as usize;
+    let (hi, lo) = chunks.split_at(chunks.len() - (1 << j));
+    let mut limbs = mul(&convert(hi, powers), &powers[j]);
+    let lo = convert(lo, powers);
+    limbs.push(0);
+    add_shifted(&mut limbs, &lo, 0);
+    trim(limbs)
+}
+
+/// Parses an unsigned integer of any length, `r"\+?[0-9]+$"`, into little-endian `u64` limbs.
+/// The value zero has no limbs.
+///
+/// Digits are parsed 16 at a time, and the chunks are combined by divide and conquer with
+/// Karatsuba multiplication, so long inputs take sub-quadratic time.
+///
+/// ```
+/// use fast_atoi::parse_biguint;
+///
+/// assert_eq!(parse_biguint(b"18446744073709551616"), Ok(vec![0, 1]));
+/// assert_eq!(parse_biguint(b"0"), Ok(vec![]));
+/// assert!(parse_biguint(b"1e9").is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn parse_biguint(text: &[u8]) -> Result<Vec<u64>, ()> {
+    let s = significant_digits(text)?;
+    let mut chunks = Vec::with_capacity((s.len() + 15) / 16);
+    for_each_chunk(s, |chunk| chunks.push(chunk))?;
+
+    let mut powers = alloc::vec![alloc::vec![CHUNK]];
+    while chunks.len() > CONVERT_THRESHOLD && 1 << powers.len() < chunks.len() {
+        let last = powers.last().unwrap();
+        let square = trim(mul(last, last));
+        powers.push(square);
+    }
+    Ok(convert(&chunks, &powers))
+}
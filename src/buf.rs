@@ -0,0 +1,50 @@
+use crate::core::count_digits;
+use crate::{FromRadix10Checked, IncrementalParser, Progress};
+use bytes::Buf;
+
+#[cold]
+fn get_atoi_split<I: FromRadix10Checked, B: Buf>(buf: &mut B) -> Result<I, ()> {
+    let mut parser = IncrementalParser::<I>::new();
+    while buf.has_remaining() {
+        let chunk = buf.chunk();
+        match parser.feed(chunk) {
+            Progress::Done(n) => {
+                buf.advance(n);
+                break;
+            }
+            Progress::NeedMore => {
+                let n = chunk.len();
+                buf.advance(n);
+            }
+        }
+    }
+    parser.finish()
+}
+
+/// Parses the integer at the start of a [`Buf`] and advances past it.
+///
+/// The integer is the longest prefix of the pattern `r"(\+|-)?[0-9]*"` and must be valid for
+/// [`crate::atoi`]. The buffer is advanced past that prefix whether or not it parses. When the
+/// integer is contained in [`Buf::chunk`] it is parsed in place; otherwise it is folded in chunk
+/// by chunk with an [`IncrementalParser`].
+///
+/// ```
+/// use bytes::Buf;
+/// use fast_atoi::get_atoi;
+///
+/// let mut buf = (&b"12"[..]).chain(&b"34\r\n"[..]);
+/// assert_eq!(get_atoi::<u32>(&mut buf), Ok(1234));
+/// assert_eq!(buf.remaining(), 2);
+/// ```
+#[inline]
+pub fn get_atoi<I: FromRadix10Checked>(buf: &mut impl Buf) -> Result<I, ()> {
+    let chunk = buf.chunk();
+    let sign = matches!(chunk.first(), Some(b'+' | b'-')) as usize;
+    let len = sign + count_digits(&chunk[sign..]);
+    if len < chunk.len() || len == buf.remaining() {
+        let res = I::from_radix_10_checked(&chunk[..len]);
+        buf.advance(len);
+        return res;
+    }
+    get_atoi_split(buf)
+}
@@ -0,0 +1,228 @@
+//! C ABI exports, for linking fast-atoi into C and C++ programs.
+//!
+//! Every integer type has a `fast_atoi_<type>` function that parses `len` bytes at `text` with the
+//! grammar of [`atoi`] and returns a [`FastAtoiStatus`]. The C declarations are in
+//! `include/fast_atoi.h`, generated from this module by cbindgen.
+//!
+//! ```c
+//! uint64_t x;
+//! if (fast_atoi_u64("12345", 5, &x) == FAST_ATOI_STATUS_OK) {
+//!     printf("%" PRIu64 "\n", x);
+//! }
+//! ```
+//!
+//! The value is written to `out` only on success, and a null `out` just validates the input.
+//!
+//! The shared and static libraries are built with
+//! `cargo rustc --release --features capi --crate-type cdylib` (or `staticlib`).
+
+use crate::core::is_integer_syntax;
+use crate::{atoi, FromRadix10Checked};
+use std::os::raw::c_char;
+
+/// The result of a `fast_atoi_<type>` function.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FastAtoiStatus {
+    /// The input was parsed and the value written to `out`.
+    Ok = 0,
+    /// The input is not an integer, or `text` is null with a non-zero `len`.
+    Invalid = 1,
+    /// The input is an integer, but does not fit in the target type.
+    Overflow = 2,
+}
+
+/// Returns the `len` bytes at `text`, or `None` for a null `text` with a non-zero `len`.
+#[inline]
+unsafe fn bytes<'a>(text: *const c_char, len: usize) -> Option<&'a [u8]> {
+    match (text.is_null(), len) {
+        (true, 0) => Some(&[]),
+        (true, _) => None,
+        (false, _) => Some(core::slice::from_raw_parts(text as *const u8, len)),
+    }
+}
+
+/// Tells apart the two ways `text` can fail to parse.
+#[cold]
+fn classify(text: &[u8], signed: bool) -> FastAtoiStatus {
+    let signs: &[u8] = if signed { b"+-" } else { b"+" };
+    match is_integer_syntax(text, signs) {
+        true => FastAtoiStatus::Overflow,
+        false => FastAtoiStatus::Invalid,
+    }
+}
+
+#[inline(always)]
+unsafe fn parse<I: FromRadix10Checked>(
+    text: *const c_char,
+    len: usize,
+    out: *mut I,
+    signed: bool,
+) -> FastAtoiStatus {
+    let text = match bytes(text, len) {
+        Some(text) => text,
+        None => return FastAtoiStatus::Invalid,
+    };
+    match atoi::<I>(text) {
+        Ok(x) => {
+            if !out.is_null() {
+                *out = x;
+            }
+            FastAtoiStatus::Ok
+        }
+        Err(()) => classify(text, signed),
+    }
+}
+
+// The exports are written out rather than generated by a macro, so that cbindgen can read them
+// without expanding macros, which needs a nightly compiler.
+
+/// Parses `len` bytes at `text` as a `u8`, with the grammar of `fast_atoi::atoi`.
+///
+/// # Safety
+///
+/// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn fast_atoi_u8(
+    text: *const c_char,
+    len: usize,
+    out: *mut u8,
+) -> FastAtoiStatus {
+    parse(text, len, out, false)
+}
+
+/// Parses `len` bytes at `text` as a `u16`, with the grammar of `fast_atoi::atoi`.
+///
+/// # Safety
+///
+/// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn fast_atoi_u16(
+    text: *const c_char,
+    len: usize,
+    out: *mut u16,
+) -> FastAtoiStatus {
+    parse(text, len, out, false)
+}
+
+/// Parses `len` bytes at `text` as a `u32`, with the grammar of `fast_atoi::atoi`.
+///
+/// # Safety
+///
+/// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn fast_atoi_u32(
+    text: *const c_char,
+    len: usize,
+    out: *mut u32,
+) -> FastAtoiStatus {
+    parse(text, len, out, false)
+}
+
+/// Parses `len` bytes at `text` as a `u64`, with the grammar of `fast_atoi::atoi`.
+///
+/// # Safety
+///
+/// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn fast_atoi_u64(
+    text: *const c_char,
+    len: usize,
+    out: *mut u64,
+) -> FastAtoiStatus {
+    parse(text, len, out, false)
+}
+
+/// Parses `len` bytes at `text` as a `u128`, with the grammar of `fast_atoi::atoi`.
+///
+/// # Safety
+///
+/// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn fast_atoi_u128(
+    text: *const c_char,
+    len: usize,
+    out: *mut u128,
+) -> FastAtoiStatus {
+    parse(text, len, out, false)
+}
+
+/// Parses `len` bytes at `text` as an `i8`, with the grammar of `fast_atoi::atoi`.
+///
+/// # Safety
+///
+/// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn fast_atoi_i8(
+    text: *const c_char,
+    len: usize,
+    out: *mut i8,
+) -> FastAtoiStatus {
+    parse(text, len, out, true)
+}
+
+/// Parses `len` bytes at `text` as an `i16`, with the grammar of `fast_atoi::atoi`.
+///
+/// # Safety
+///
+/// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn fast_atoi_i16(
+    text: *const c_char,
+    len: usize,
+    out: *mut i16,
+) -> FastAtoiStatus {
+    parse(text, len, out, true)
+}
+
+/// Parses `len` bytes at `text` as an `i32`, with the grammar of `fast_atoi::atoi`.
+///
+/// # Safety
+///
+/// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn fast_atoi_i32(
+    text: *const c_char,
+    len: usize,
+    out: *mut i32,
+) -> FastAtoiStatus {
+    parse(text, len, out, true)
+}
+
+/// Parses `len` bytes at `text` as an `i64`, with the grammar of `fast_atoi::atoi`.
+///
+/// # Safety
+///
+/// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn fast_atoi_i64(
+    text: *const c_char,
+    len: usize,
+    out: *mut i64,
+) -> FastAtoiStatus {
+    parse(text, len, out, true)
+}
+
+/// Parses `len` bytes at `text` as an `i128`, with the grammar of `fast_atoi::atoi`.
+///
+/// # Safety
+///
+/// `text` must be valid for reads of `len` bytes, or null if `len` is 0. `out` must be null or
+/// valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn fast_atoi_i128(
+    text: *const c_char,
+    len: usize,
+    out: *mut i128,
+) -> FastAtoiStatus {
+    parse(text, len, out, true)
+}
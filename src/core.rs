@@ -1,3 +1,6 @@
+use crate::simd::{dispatch_sse2, dispatch_sse41};
+use crate::FromRadix10CheckedNoPlus;
+
 #[cold]
 pub(crate) fn parse_plus_sign(s: &mut &[u8]) {
     *s = &s[1..];
@@ -10,6 +13,72 @@ pub(crate) fn strip_leading_zeros(s: &mut &[u8], until: usize) {
     }
 }
 
+#[cold]
+pub(crate) fn is_all_zeros(s: &[u8]) -> bool {
+    s.iter().all(|&b| b == b'0')
+}
+
+/// Returns the number of leading ASCII digits in `s`, checking 8 bytes at a time.
+#[inline]
+pub(crate) fn count_digits(s: &[u8]) -> usize {
+    let mut n = 0;
+    while s.len() - n >= 8 {
+        let u = u64::from_le_bytes(s[n..n + 8].try_into().unwrap()) ^ 0x3030303030303030;
+        // A carry out of a bad byte can only flag later bytes, so the lowest flag is exact.
+        let bad = (u | u.wrapping_add(0x0606060606060606)) & 0xf0f0f0f0f0f0f0f0;
+        if bad != 0 {
+            return n + (bad.trailing_zeros() >> 3) as usize;
+        }
+        n += 8;
+    }
+    while n < s.len() && s[n].is_ascii_digit() {
+        n += 1;
+    }
+    n
+}
+
+/// Returns whether `s` is an optional sign from `signs` followed by one or more digits. A parser
+/// for that grammar can only reject such input because the value overflows.
+#[cold]
+pub(crate) fn is_integer_syntax(s: &[u8], signs: &[u8]) -> bool {
+    let sign = matches!(s.first(), Some(c) if signs.contains(c)) as usize;
+    s.len() > sign && count_digits(&s[sign..]) == s.len() - sign
+}
+
+/// The most fraction digits that [`scale_fraction`] reads; later digits are truncated.
+const MAX_FRAC_DIGITS: usize = 19;
+
+/// Splits a `[0-9]*(\.[0-9]+)?` number from the start of `s` into its integer and fraction
+/// digits, and advances `s` past it. A '.' without digits after it is an error.
+///
+/// Both parts are all digits, so a non-empty integer part can only fail to parse by overflowing.
+#[inline]
+pub(crate) fn split_decimal<'a>(s: &mut &'a [u8]) -> Result<(&'a [u8], &'a [u8]), ()> {
+    let (int, rest) = s.split_at(count_digits(s));
+    let (frac, rest) = match rest {
+        [b'.', frac @ ..] => match count_digits(frac) {
+            0 => return error(),
+            n => frac.split_at(n),
+        },
+        _ => (&rest[..0], rest),
+    };
+    *s = rest;
+    Ok((int, frac))
+}
+
+/// Returns `unit` times the fraction `0.<frac>`, rounded down. `frac` must be all digits.
+///
+/// Only the first 19 digits are read, which always fit in a `u64`, so the product fits in a
+/// `u128` for any `unit` below `2^64`.
+#[inline]
+pub(crate) fn scale_fraction(frac: &[u8], unit: u128) -> u128 {
+    debug_assert!(unit >> 64 == 0);
+    let n = frac.len().min(MAX_FRAC_DIGITS);
+    // Only an empty `frac` fails to parse, and it is zero.
+    let digits = u64::from_radix_10_checked_no_plus(&frac[..n]).unwrap_or(0);
+    digits as u128 * unit / 10u128.pow(n as u32)
+}
+
 #[cold]
 pub(crate) fn error<T>() -> Result<T, ()> {
     Err(())
@@ -98,7 +167,7 @@ pub fn parse_4_to_8(s: &mut &[u8], err: &mut u64, len: usize) -> u64 {
     *s = &s[len - 4..];
     u ^= 0x30303030;
     let offset = 8 - len;
-    u = u << (offset << 3);
+    u <<= offset << 3;
     let hi = swar_4(u, err) as u64;
     fold_4(s, err, hi)
 }
@@ -109,7 +178,7 @@ pub fn parse_8_to_16(s: &mut &[u8], err: &mut u64, len: usize) -> u64 {
     *s = &s[len - 8..];
     u ^= 0x3030303030303030;
     let offset = 16 - len;
-    u = u << (offset << 3);
+    u <<= offset << 3;
     let hi = swar_8(u, err);
     fold_8(s, err, hi)
 }
@@ -196,29 +265,106 @@ pub(crate) fn parse_up_to_38(s: &mut &[u8], err: &mut u64) -> u128 {
 
 /// Parses exactly 16 bytes into the `u128`.
 /// If there's an error, `is_err` is set to a non-zero value.
-#[allow(unreachable_code)]
 #[inline(always)]
 pub fn parse_16(s: &mut &[u8], err: &mut u64) -> u64 {
-    #[cfg(all(feature = "std", target_arch = "x86_64", not(miri)))]
-    {
-        if std::is_x86_feature_detected!("sse4.1") {
-            unsafe {
-                return crate::simd::parse_16(s, err);
-            }
-        }
-    }
+    dispatch_sse41!(parse_16(s, err))
+}
 
-    #[cfg(all(
-        not(feature = "std"),
-        feature = "sse41",
-        target_arch = "x86_64",
-        not(miri)
-    ))]
-    {
-        unsafe {
-            return crate::simd::parse_16(s, err);
-        }
-    }
+/// Sets the high bit of every byte of `x` in `lo..=hi`. Bytes must be below 0x80.
+#[inline(always)]
+const fn bytes_in_range(x: u64, lo: u8, hi: u8) -> u64 {
+    let a = x.wrapping_add(0x0101010101010101 * (0x80 - lo as u64));
+    let b = x.wrapping_add(0x0101010101010101 * (0x7f - hi as u64));
+    a & !b & 0x8080808080808080
+}
 
-    crate::fallback::parse_16(s, err)
+#[inline(always)]
+fn swar_hex_8(u: u64, err: &mut u64) -> u64 {
+    let lower = u | 0x2020202020202020;
+    let digit = bytes_in_range(u, b'0', b'9');
+    let alpha = bytes_in_range(lower, b'a', b'f');
+    *err |= ((digit | alpha) ^ 0x8080808080808080) | (u & 0x8080808080808080);
+    // n7, n6, ..., n0 (the first byte is the most significant nibble)
+    let mut n = (lower & 0x0f0f0f0f0f0f0f0f) + (alpha >> 7) * 9;
+    // 16 * n7 + n6, 16 * n5 + n4, 16 * n3 + n2, 16 * n1 + n0
+    n = ((n << 4) | (n >> 8)) & 0x00ff00ff00ff00ff;
+    // 256 * (16 * n7 + n6) + (16 * n5 + n4), 256 * (16 * n3 + n2) + (16 * n1 + n0)
+    n = ((n << 8) | (n >> 16)) & 0x0000ffff0000ffff;
+    ((n << 16) | (n >> 32)) & 0xffffffff
+}
+
+/// Parses exactly 2 hex bytes (either case) into the `u64`.
+/// If there's an error, `is_err` is set to a non-zero value.
+#[inline]
+pub fn parse_hex_2(s: &mut &[u8], err: &mut u64) -> u64 {
+    let u = read_u16(s) as u64 | 0x3030303030300000;
+    swar_hex_8(u, err) >> 24
+}
+
+/// Parses exactly 4 hex bytes (either case) into the `u64`.
+/// If there's an error, `is_err` is set to a non-zero value.
+#[inline]
+pub fn parse_hex_4(s: &mut &[u8], err: &mut u64) -> u64 {
+    let u = read_u32(s) as u64 | 0x3030303000000000;
+    swar_hex_8(u, err) >> 16
+}
+
+/// Parses exactly 8 hex bytes (either case) into the `u64`.
+/// If there's an error, `is_err` is set to a non-zero value.
+#[inline]
+pub fn parse_hex_8(s: &mut &[u8], err: &mut u64) -> u64 {
+    let u = read_u64(s);
+    swar_hex_8(u, err)
+}
+
+#[inline(always)]
+fn advance<'a>(s: &mut &'a mut [u8], n: usize) -> &'a mut [u8] {
+    let (head, tail) = core::mem::take(s).split_at_mut(n);
+    *s = tail;
+    head
+}
+
+/// Splits every 32-bit lane of `u`, each below 10000, into 2 digit pairs, then every pair into
+/// 2 digits, and returns the ASCII bytes. The low lane is written first.
+#[inline(always)]
+fn swar_digits_8(u: u64) -> u64 {
+    // 100 * p1 + p0 => p1, p0 in 16-bit lanes (10486 / 2^20 divides by 100 below 43699)
+    let hi = ((u * 10486) >> 20) & 0x0000007f0000007f;
+    let u = hi | ((u - hi * 100) << 16);
+    // 10 * d1 + d0 => d1, d0 in 8-bit lanes (103 / 2^10 divides by 10 below 179)
+    let hi = ((u * 103) >> 10) & 0x000f000f000f000f;
+    (hi | ((u - hi * 10) << 8)) | 0x3030303030303030
+}
+
+/// Writes `n`, which must be below 100, as exactly 2 zero-padded bytes.
+#[inline]
+pub fn write_2(s: &mut &mut [u8], n: u64) {
+    debug_assert!(n < 100);
+    let hi = (n * 103) >> 10;
+    let u = (hi | ((n - hi * 10) << 8)) as u16 | 0x3030;
+    advance(s, 2).copy_from_slice(&u.to_le_bytes());
+}
+
+/// Writes `n`, which must be below 10^4, as exactly 4 zero-padded bytes.
+#[inline]
+pub fn write_4(s: &mut &mut [u8], n: u64) {
+    debug_assert!(n < 10_000);
+    let u = swar_digits_8(n << 32) >> 32;
+    advance(s, 4).copy_from_slice(&(u as u32).to_le_bytes());
+}
+
+/// Writes `n`, which must be below 10^8, as exactly 8 zero-padded bytes.
+#[inline]
+pub fn write_8(s: &mut &mut [u8], n: u64) {
+    debug_assert!(n < 100_000_000);
+    let u = swar_digits_8((n / 10_000) | ((n % 10_000) << 32));
+    advance(s, 8).copy_from_slice(&u.to_le_bytes());
+}
+
+/// Writes `n`, which must be below 10^16, as exactly 16 zero-padded bytes.
+#[inline(always)]
+pub fn write_16(s: &mut &mut [u8], n: u64) {
+    debug_assert!(n < 10_000_000_000_000_000);
+    let s = advance(s, 16);
+    dispatch_sse2!(write_16(s, n))
 }
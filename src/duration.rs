@@ -0,0 +1,103 @@
+use crate::core::{scale_fraction, split_decimal};
+use crate::FromRadix10CheckedNoPlus;
+use core::time::Duration;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// The reason a duration could not be parsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DurationError {
+    /// The input is not a valid duration.
+    Invalid,
+    /// The input is a valid duration, but does not fit in a [`Duration`].
+    Overflow,
+}
+
+impl core::fmt::Display for DurationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            DurationError::Invalid => "invalid duration",
+            DurationError::Overflow => "duration out of range",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DurationError {}
+
+/// Returns the length in nanoseconds of a unit suffix.
+#[inline]
+fn unit_nanos(unit: &[u8]) -> Option<u128> {
+    Some(match unit {
+        b"ns" => 1,
+        // "us", then "µs" with MICRO SIGN and with GREEK SMALL LETTER MU.
+        b"us" | b"\xc2\xb5s" | b"\xce\xbcs" => 1_000,
+        b"ms" => 1_000_000,
+        b"s" => NANOS_PER_SEC,
+        b"m" => 60 * NANOS_PER_SEC,
+        b"h" => 3_600 * NANOS_PER_SEC,
+        b"d" => 86_400 * NANOS_PER_SEC,
+        _ => return None,
+    })
+}
+
+/// Parses one `[0-9]+(\.[0-9]+)?` number from the start of `s` and the unit after it, returning
+/// the number of nanoseconds.
+#[inline]
+fn component(s: &mut &[u8], is_bare: bool) -> Result<u128, DurationError> {
+    let mut rest = *s;
+    let (int, frac) = split_decimal(&mut rest).map_err(|_| DurationError::Invalid)?;
+    if int.is_empty() {
+        return Err(DurationError::Invalid);
+    }
+    let unit_len = rest
+        .iter()
+        .take_while(|&&b| !b.is_ascii_digit() && b != b'.')
+        .count();
+    let unit = match (&rest[..unit_len], is_bare) {
+        (b"", true) => NANOS_PER_SEC,
+        (u, _) => match unit_nanos(u) {
+            Some(unit) => unit,
+            None => return Err(DurationError::Invalid),
+        },
+    };
+    *s = &rest[unit_len..];
+
+    let int = u128::from_radix_10_checked_no_plus(int).map_err(|_| DurationError::Overflow)?;
+    let nanos = int.checked_mul(unit).ok_or(DurationError::Overflow)?;
+    nanos
+        .checked_add(scale_fraction(frac, unit))
+        .ok_or(DurationError::Overflow)
+}
+
+/// Parses a human-friendly duration such as `1h30m`, `250ms`, `1.5s` or `90`.
+///
+/// A duration is one or more components of the form `[0-9]+(\.[0-9]+)?<unit>`, where the unit is
+/// one of `ns`, `us` (or `µs`), `ms`, `s`, `m`, `h` and `d`. A lone number without a unit is a
+/// number of seconds. Units may repeat and come in any order; the components are summed.
+///
+/// Fractions are exact down to the nanosecond and truncated below it. A duration that does not
+/// fit in a [`Duration`] is [`DurationError::Overflow`].
+///
+/// ```
+/// use core::time::Duration;
+/// use fast_atoi::*;
+///
+/// assert_eq!(parse_duration(b"1h30m"), Ok(Duration::from_secs(5400)));
+/// assert_eq!(parse_duration(b"250ms"), Ok(Duration::from_millis(250)));
+/// assert_eq!(parse_duration(b"1.5s"), Ok(Duration::from_millis(1500)));
+/// assert_eq!(parse_duration(b"90"), Ok(Duration::from_secs(90)));
+/// assert_eq!(parse_duration(b"1h30"), Err(DurationError::Invalid));
+/// assert_eq!(parse_duration(b"18446744073709551616s"), Err(DurationError::Overflow));
+/// ```
+#[inline]
+pub fn parse_duration(mut s: &[u8]) -> Result<Duration, DurationError> {
+    let mut total = component(&mut s, true)?;
+    while !s.is_empty() {
+        total = total
+            .checked_add(component(&mut s, false)?)
+            .ok_or(DurationError::Overflow)?;
+    }
+    let secs = u64::try_from(total / NANOS_PER_SEC).map_err(|_| DurationError::Overflow)?;
+    Ok(Duration::new(secs, (total % NANOS_PER_SEC) as u32))
+}
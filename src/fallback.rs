@@ -1,9 +1,88 @@
 #![allow(dead_code)]
 
-use crate::core::{fold_8, parse_8};
+use crate::core::{count_digits, fold_8, parse_1, parse_2, parse_3, parse_8, parse_hex_8, write_8};
 
 #[inline]
 pub(crate) fn parse_16(s: &mut &[u8], err: &mut u64) -> u64 {
     let hi = parse_8(s, err);
     fold_8(s, err, hi)
 }
+
+#[inline]
+pub(crate) fn write_16(mut s: &mut [u8], n: u64) {
+    write_8(&mut s, n / 100_000_000);
+    write_8(&mut s, n % 100_000_000);
+}
+
+#[inline]
+pub(crate) fn whitespace_16(s: &[u8]) -> u32 {
+    s[..16].iter().enumerate().fold(0, |mask, (i, b)| {
+        mask | (b.is_ascii_whitespace() as u32) << i
+    })
+}
+
+#[inline]
+pub(crate) fn narrow_16(src: &[u16], dst: &mut [u8]) {
+    for (d, &u) in dst[..16].iter_mut().zip(&src[..16]) {
+        *d = u.min(0xff) as u8;
+    }
+}
+
+/// Parses a dotted-quad IPv4 address from the first `len` bytes of `buf`.
+#[inline]
+pub(crate) fn parse_ipv4(buf: &[u8; 16], len: usize) -> Result<[u8; 4], ()> {
+    let mut s = &buf[..len];
+    let mut octets = [0u8; 4];
+    let mut err = 0;
+    for (i, octet) in octets.iter_mut().enumerate() {
+        if i > 0 {
+            if s.first() != Some(&b'.') {
+                return Err(());
+            }
+            s = &s[1..];
+        }
+        let n = count_digits(s);
+        err |= (n > 1 && s[0] == b'0') as u64;
+        let x = match n {
+            1 => parse_1(&mut s, &mut err),
+            2 => parse_2(&mut s, &mut err),
+            3 => parse_3(&mut s, &mut err),
+            _ => return Err(()),
+        };
+        err |= x >> 8;
+        *octet = x as u8;
+    }
+    match (err, s.is_empty()) {
+        (0, true) => Ok(octets),
+        _ => Err(()),
+    }
+}
+
+/// Parses 32 hex bytes into a `u128`.
+#[inline]
+pub(crate) fn parse_uuid_simple(mut s: &[u8]) -> Result<u128, ()> {
+    let mut err = 0;
+    let mut res = 0u128;
+    for _ in 0..4 {
+        res = res << 32 | parse_hex_8(&mut s, &mut err) as u128;
+    }
+    match err {
+        0 => Ok(res),
+        _ => Err(()),
+    }
+}
+
+/// Parses a 36 byte hyphenated UUID into a `u128`.
+#[inline]
+pub(crate) fn parse_uuid_hyphenated(s: &[u8]) -> Result<u128, ()> {
+    if s[8] != b'-' || s[13] != b'-' || s[18] != b'-' || s[23] != b'-' {
+        return Err(());
+    }
+    let mut digits = [0u8; 32];
+    digits[..8].copy_from_slice(&s[..8]);
+    digits[8..12].copy_from_slice(&s[9..13]);
+    digits[12..16].copy_from_slice(&s[14..18]);
+    digits[16..20].copy_from_slice(&s[19..23]);
+    digits[20..].copy_from_slice(&s[24..36]);
+    parse_uuid_simple(&digits)
+}
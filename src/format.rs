@@ -0,0 +1,155 @@
+use crate::core::{write_16, write_4, write_8};
+use core::marker::PhantomData;
+
+const E8: u64 = 100_000_000;
+const E16: u64 = 10_000_000_000_000_000;
+
+/// Copies `digits` to the start of `buf` without its leading zeros, keeping at least one digit.
+/// Returns the number of bytes written.
+#[inline(always)]
+fn copy_significant(digits: &[u8], buf: &mut [u8]) -> usize {
+    let zeros = digits[..digits.len() - 1]
+        .iter()
+        .take_while(|&&b| b == b'0')
+        .count();
+    let len = digits.len() - zeros;
+    buf[..len].copy_from_slice(&digits[zeros..]);
+    len
+}
+
+#[inline]
+fn write_u64(n: u64, buf: &mut [u8]) -> usize {
+    let mut digits = [0u8; 20];
+    let mut s = &mut digits[..];
+    if n < E8 {
+        write_8(&mut s, n);
+        return copy_significant(&digits[..8], buf);
+    }
+    // u64::MAX / 10^16 is 1844.
+    write_4(&mut s, n / E16);
+    write_16(&mut s, n % E16);
+    copy_significant(&digits, buf)
+}
+
+#[inline]
+fn write_u128(n: u128, buf: &mut [u8]) -> usize {
+    if n <= u64::MAX as u128 {
+        return write_u64(n as u64, buf);
+    }
+    let mut digits = [0u8; 40];
+    let mut s = &mut digits[..];
+    // u128::MAX / 10^32 is 3402823.
+    let (hi, lo) = (n / (E16 as u128), (n % (E16 as u128)) as u64);
+    write_8(&mut s, (hi / E16 as u128) as u64);
+    write_16(&mut s, (hi % E16 as u128) as u64);
+    write_16(&mut s, lo);
+    copy_significant(&digits, buf)
+}
+
+/// Formats an integer in decimal, the inverse of [`crate::FromRadix10Checked`].
+pub trait ToRadix10 {
+    /// The length of the longest value, sign included.
+    const MAX_LEN: usize;
+
+    /// Writes `self` in decimal to the start of `buf` and returns the written bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is too short for the value. [`Self::MAX_LEN`] bytes are always enough.
+    fn to_radix_10(self, buf: &mut [u8]) -> &[u8];
+}
+
+macro_rules! impl_unsigned_to_radix_10 {
+    ($type:ty, $max_len:literal) => {
+        impl ToRadix10 for $type {
+            const MAX_LEN: usize = $max_len;
+
+            #[inline]
+            fn to_radix_10(self, buf: &mut [u8]) -> &[u8] {
+                let len = write_u64(self as u64, buf);
+                &buf[..len]
+            }
+        }
+    };
+}
+
+macro_rules! impl_signed_to_radix_10 {
+    ($type:ty, $max_len:literal) => {
+        impl ToRadix10 for $type {
+            const MAX_LEN: usize = $max_len;
+
+            #[inline]
+            fn to_radix_10(self, buf: &mut [u8]) -> &[u8] {
+                let sign = (self < 0) as usize;
+                if self < 0 {
+                    buf[0] = b'-';
+                }
+                let len = write_u64(self.unsigned_abs() as u64, &mut buf[sign..]);
+                &buf[..sign + len]
+            }
+        }
+    };
+}
+
+impl_unsigned_to_radix_10!(u8, 3);
+impl_unsigned_to_radix_10!(u16, 5);
+impl_unsigned_to_radix_10!(u32, 10);
+impl_unsigned_to_radix_10!(u64, 20);
+impl_signed_to_radix_10!(i8, 4);
+impl_signed_to_radix_10!(i16, 6);
+impl_signed_to_radix_10!(i32, 11);
+impl_signed_to_radix_10!(i64, 20);
+
+impl ToRadix10 for u128 {
+    const MAX_LEN: usize = 39;
+
+    #[inline]
+    fn to_radix_10(self, buf: &mut [u8]) -> &[u8] {
+        let len = write_u128(self, buf);
+        &buf[..len]
+    }
+}
+
+impl ToRadix10 for i128 {
+    const MAX_LEN: usize = 40;
+
+    #[inline]
+    fn to_radix_10(self, buf: &mut [u8]) -> &[u8] {
+        let sign = (self < 0) as usize;
+        if self < 0 {
+            buf[0] = b'-';
+        }
+        let len = write_u128(self.unsigned_abs(), &mut buf[sign..]);
+        &buf[..sign + len]
+    }
+}
+
+/// Fails to compile when `N` bytes are not enough for every value of `I`.
+struct AssertFits<I, const N: usize>(PhantomData<I>);
+
+impl<I: ToRadix10, const N: usize> AssertFits<I, N> {
+    const OK: () = assert!(N >= I::MAX_LEN, "buffer shorter than ToRadix10::MAX_LEN");
+}
+
+/// Writes an integer in decimal to the start of `buf` and returns the written bytes, the inverse
+/// of [`crate::atoi`].
+///
+/// `buf` must hold at least [`ToRadix10::MAX_LEN`] bytes, which is checked at compile time:
+///
+/// ```compile_fail
+/// let mut buf = [0u8; 4];
+/// fast_atoi::write_radix10(u64::MAX, &mut buf);
+/// ```
+///
+/// ```
+/// use fast_atoi::*;
+///
+/// let mut buf = [0u8; 20];
+/// assert_eq!(write_radix10(-1234i64, &mut buf), b"-1234");
+/// assert_eq!(write_radix10(u64::MAX, &mut buf), b"18446744073709551615");
+/// ```
+#[inline(always)]
+pub fn write_radix10<I: ToRadix10, const N: usize>(value: I, buf: &mut [u8; N]) -> &[u8] {
+    let () = AssertFits::<I, N>::OK;
+    value.to_radix_10(buf)
+}
@@ -0,0 +1,137 @@
+use crate::core::{count_digits, error, parse_1, parse_16, parse_8};
+use crate::{FromRadix10Checked, ToRadix10};
+use core::marker::PhantomData;
+
+/// The result of feeding a chunk to an [`IncrementalParser`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Progress {
+    /// The whole chunk was consumed, and the number may continue in the next one.
+    NeedMore,
+    /// The number ended after this many bytes of the chunk. The rest of the chunk was not consumed.
+    Done(usize),
+}
+
+/// A push parser for an integer that arrives in chunks, e.g. from a socket.
+///
+/// Each chunk is folded into the partial value as it arrives with the crate's fixed-width blocks,
+/// so the token is never buffered. The number ends at the first byte that is not a digit (or a
+/// leading sign). [`IncrementalParser::finish`] accepts exactly what [`crate::atoi`] accepts.
+///
+/// ```
+/// use fast_atoi::{IncrementalParser, Progress};
+///
+/// let mut parser = IncrementalParser::<i64>::new();
+/// assert_eq!(parser.feed(b"-12"), Progress::NeedMore);
+/// assert_eq!(parser.feed(b"345"), Progress::NeedMore);
+/// assert_eq!(parser.feed(b"67\r\n"), Progress::Done(2));
+/// assert_eq!(parser.finish(), Ok(-1234567));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct IncrementalParser<T> {
+    /// The magnitude of the digits so far.
+    value: u128,
+    digits: usize,
+    sign: Option<u8>,
+    overflow: bool,
+    done: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<T: FromRadix10Checked> Default for IncrementalParser<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FromRadix10Checked> IncrementalParser<T> {
+    /// Creates a parser that has not consumed any input yet.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            value: 0,
+            digits: 0,
+            sign: None,
+            overflow: false,
+            done: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of digits consumed so far, leading zeros included.
+    #[inline]
+    pub fn digits(&self) -> usize {
+        self.digits
+    }
+
+    #[inline]
+    fn push(&mut self, x: u64, scale: u128) {
+        match self
+            .value
+            .checked_mul(scale)
+            .and_then(|v| v.checked_add(x as u128))
+        {
+            Some(v) if !self.overflow => self.value = v,
+            _ => self.overflow = true,
+        }
+    }
+
+    /// Consumes the start of `chunk` that continues the number.
+    #[inline]
+    pub fn feed(&mut self, mut chunk: &[u8]) -> Progress {
+        if self.done {
+            return Progress::Done(0);
+        }
+        let mut sign = 0;
+        if self.digits == 0 && self.sign.is_none() && !chunk.is_empty() {
+            if let b @ (b'+' | b'-') = chunk[0] {
+                self.sign = Some(b);
+                chunk = &chunk[1..];
+                sign = 1;
+            }
+        }
+        let n = count_digits(chunk);
+        self.digits += n;
+
+        let mut s = &chunk[..n];
+        // The digits are already validated, so the blocks cannot report an error.
+        let mut err = 0;
+        while s.len() >= 16 {
+            let x = parse_16(&mut s, &mut err);
+            self.push(x, 10_000_000_000_000_000);
+        }
+        if s.len() >= 8 {
+            let x = parse_8(&mut s, &mut err);
+            self.push(x, 100_000_000);
+        }
+        while !s.is_empty() {
+            let x = parse_1(&mut s, &mut err);
+            self.push(x, 10);
+        }
+        debug_assert_eq!(err, 0);
+
+        if n < chunk.len() {
+            self.done = true;
+            return Progress::Done(sign + n);
+        }
+        Progress::NeedMore
+    }
+
+    /// Returns the parsed integer, with the same grammar and overflow checks as [`crate::atoi`].
+    #[inline]
+    pub fn finish(self) -> Result<T, ()> {
+        if self.overflow {
+            return error::<T>();
+        }
+        let mut buf = [0u8; 40];
+        let mut len = 0;
+        if let Some(sign) = self.sign {
+            buf[0] = sign;
+            len = 1;
+        }
+        if self.digits > 0 {
+            len += self.value.to_radix_10(&mut buf[len..]).len();
+        }
+        T::from_radix_10_checked(&buf[..len])
+    }
+}
@@ -0,0 +1,69 @@
+use crate::core::{count_digits, strip_leading_zeros};
+use crate::{FromRadix10Checked, ToRadix10};
+
+/// A primitive integer type, as returned by [`infer_int_type`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IntKind {
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+}
+
+/// Returns the kind of the first type in the list that holds the `$len` significant digits of
+/// `$text`. Fewer digits than the type's longest value always fit, and only the type whose longest
+/// value has exactly `$len` digits has to parse `$text` to find out.
+macro_rules! first_fit {
+    ($text:expr, $len:expr, $sign:expr, $($type:ty => $kind:ident),*) => {{
+        $(
+            let max = <$type as ToRadix10>::MAX_LEN - $sign;
+            if $len < max || ($len == max && <$type>::from_radix_10_checked($text).is_ok()) {
+                return Some(IntKind::$kind);
+            }
+        )*
+        None
+    }};
+}
+
+/// Returns the narrowest integer type that [`crate::atoi`] can parse `text` into, or `None` if
+/// no type can hold it.
+///
+/// Non-negative values are given an unsigned type and negative values a signed type, so a column
+/// holding both `200` and `-1` needs the wider signed type of the two, `i16`. The exception is
+/// `-0`, which is given `i8` because the unsigned types reject the '-'.
+///
+/// ```
+/// use fast_atoi::*;
+///
+/// assert_eq!(infer_int_type(b"200"), Some(IntKind::U8));
+/// assert_eq!(infer_int_type(b"-129"), Some(IntKind::I16));
+/// assert_eq!(infer_int_type(b"4294967296"), Some(IntKind::U64));
+/// assert_eq!(infer_int_type(b"12.5"), None);
+/// ```
+#[inline]
+pub fn infer_int_type(text: &[u8]) -> Option<IntKind> {
+    let (negative, mut digits) = match text {
+        [b'-', rest @ ..] => (true, rest),
+        [b'+', rest @ ..] => (false, rest),
+        _ => (false, text),
+    };
+    if digits.is_empty() || count_digits(digits) != digits.len() {
+        return None;
+    }
+    strip_leading_zeros(&mut digits, 0);
+    let len = digits.len();
+    match negative {
+        true => {
+            first_fit!(text, len, 1, i8 => I8, i16 => I16, i32 => I32, i64 => I64, i128 => I128)
+        }
+        false => {
+            first_fit!(text, len, 0, u8 => U8, u16 => U16, u32 => U32, u64 => U64, u128 => U128)
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::core::{count_digits, is_integer_syntax};
+use crate::{atoi_with, FromRadix10Checked, ParseOptions};
+
+/// RFC 8259 integers: canonical, except that "-0" is a valid JSON number.
+const JSON: ParseOptions = ParseOptions::CANONICAL.negative_zero(true);
+
+/// The reason a JSON number token could not be parsed as an integer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum JsonIntError {
+    /// The input is not a valid JSON number.
+    Invalid,
+    /// The input is a valid JSON number, but has a fraction or an exponent.
+    NotAnInteger,
+    /// The input is a valid JSON integer, but does not fit in the target type.
+    /// For unsigned types this includes every negative number, including "-0".
+    OutOfRange,
+}
+
+impl core::fmt::Display for JsonIntError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            JsonIntError::Invalid => "invalid JSON number",
+            JsonIntError::NotAnInteger => "JSON number is not an integer",
+            JsonIntError::OutOfRange => "JSON integer out of range",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for JsonIntError {}
+
+/// Returns the length of the integer part `-?(0|[1-9][0-9]*)` at the start of `s`.
+#[inline]
+fn int_len(s: &[u8]) -> Result<usize, JsonIntError> {
+    let sign = (!s.is_empty() && s[0] == b'-') as usize;
+    match count_digits(&s[sign..]) {
+        0 => Err(JsonIntError::Invalid),
+        n if n > 1 && s[sign] == b'0' => Err(JsonIntError::Invalid),
+        n => Ok(sign + n),
+    }
+}
+
+/// Returns the length of the `(\.[0-9]+)?([eE][+-]?[0-9]+)?` part at the start of `s`.
+#[cold]
+fn frac_exp_len(s: &[u8]) -> Result<usize, JsonIntError> {
+    let mut n = 0;
+    if n < s.len() && s[n] == b'.' {
+        n += 1;
+        match count_digits(&s[n..]) {
+            0 => return Err(JsonIntError::Invalid),
+            d => n += d,
+        }
+    }
+    if n < s.len() && (s[n] == b'e' || s[n] == b'E') {
+        n += 1;
+        if n < s.len() && (s[n] == b'+' || s[n] == b'-') {
+            n += 1;
+        }
+        match count_digits(&s[n..]) {
+            0 => return Err(JsonIntError::Invalid),
+            d => n += d,
+        }
+    }
+    Ok(n)
+}
+
+#[cold]
+fn classify(token: &[u8]) -> JsonIntError {
+    let int = match int_len(token) {
+        Ok(n) => n,
+        Err(e) => return e,
+    };
+    // `int_len` has rejected redundant zeros, so a token that is only an integer overflowed.
+    if is_integer_syntax(token, b"-") {
+        return JsonIntError::OutOfRange;
+    }
+    match frac_exp_len(&token[int..]) {
+        Ok(n) if int + n == token.len() => JsonIntError::NotAnInteger,
+        Ok(_) => JsonIntError::Invalid,
+        Err(e) => e,
+    }
+}
+
+/// Parses a JSON number token into an integer.
+///
+/// The whole token must match RFC 8259 `number`, and the number must be an integer:
+/// `r"-?(0|[1-9][0-9]*)$"`. Unlike [`crate::atoi`], '+' and redundant leading zeros are
+/// rejected.
+///
+/// ```
+/// use fast_atoi::*;
+///
+/// assert_eq!(atoi_json::<i64>(b"-42"), Ok(-42));
+/// assert_eq!(atoi_json::<i64>(b"042"), Err(JsonIntError::Invalid));
+/// assert_eq!(atoi_json::<i64>(b"4.2e1"), Err(JsonIntError::NotAnInteger));
+/// assert_eq!(atoi_json::<u8>(b"420"), Err(JsonIntError::OutOfRange));
+/// ```
+#[inline]
+pub fn atoi_json<I: FromRadix10Checked>(token: &[u8]) -> Result<I, JsonIntError> {
+    match atoi_with(token, &JSON) {
+        Ok(x) => Ok(x),
+        Err(()) => Err(classify(token)),
+    }
+}
+
+/// Parses a JSON integer from the start of `buf`, returning the integer and the number of bytes
+/// consumed.
+///
+/// The number ends at the first byte that cannot continue it; whatever follows is left to the
+/// caller. A number followed by a fraction or exponent is [`JsonIntError::NotAnInteger`].
+///
+/// ```
+/// use fast_atoi::*;
+///
+/// assert_eq!(atoi_json_prefix::<u32>(b"1234, 5]"), Ok((1234, 4)));
+/// assert_eq!(atoi_json_prefix::<u32>(b"1.5]"), Err(JsonIntError::NotAnInteger));
+/// ```
+#[inline]
+pub fn atoi_json_prefix<I: FromRadix10Checked>(buf: &[u8]) -> Result<(I, usize), JsonIntError> {
+    let len = int_len(buf)?;
+    if len < buf.len() && matches!(buf[len], b'.' | b'e' | b'E') {
+        frac_exp_len(&buf[len..])?;
+        return Err(JsonIntError::NotAnInteger);
+    }
+    match atoi_with(&buf[..len], &JSON) {
+        Ok(x) => Ok((x, len)),
+        Err(()) => Err(JsonIntError::OutOfRange),
+    }
+}
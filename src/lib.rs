@@ -1,15 +1,60 @@
 #![allow(rustdoc::bare_urls)]
+#![allow(clippy::result_unit_err)]
 #![warn(unreachable_pub)]
 #![doc = include_str!("../README.md")]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod biguint;
+pub use crate::biguint::*;
+#[cfg(feature = "bytes")]
+mod buf;
+#[cfg(feature = "bytes")]
+pub use crate::buf::*;
+#[cfg(feature = "capi")]
+pub mod capi;
 mod core;
 pub use crate::core::*;
+mod duration;
+pub use crate::duration::*;
 mod fallback;
+mod format;
+pub use crate::format::*;
+mod incremental;
+pub use crate::incremental::*;
+mod infer;
+pub use crate::infer::*;
+mod json;
+pub use crate::json::*;
+pub mod net;
+#[cfg(feature = "nom")]
+pub mod nom;
+mod options;
+pub use crate::options::*;
+#[cfg(feature = "std")]
+mod scanner;
+#[cfg(feature = "std")]
+pub use crate::scanner::*;
+mod semver;
+pub use crate::semver::*;
+#[cfg(feature = "serde")]
+pub mod serde;
 mod signed;
+mod size;
+pub use crate::size::*;
+pub mod timestamp;
+mod unicode;
+pub use crate::unicode::*;
 mod unsigned;
+mod utf16;
+pub use crate::utf16::*;
+mod uuid;
+pub use crate::uuid::*;
+mod wide;
+pub use crate::wide::*;
 
-#[cfg(all(target_arch = "x86_64", not(miri)))]
 mod simd;
 
 pub trait FromRadix10Checked {
@@ -43,6 +88,62 @@ pub fn atoi_no_plus<I: FromRadix10CheckedNoPlus>(text: &[u8]) -> Result<I, ()> {
     I::from_radix_10_checked_no_plus(text)
 }
 
+/// Returns whether [`atoi`] would parse `text` into an `I`, without returning the value.
+///
+/// ```
+/// use fast_atoi::*;
+///
+/// assert!(is_valid::<u8>(b"255"));
+/// assert!(!is_valid::<u8>(b"256"));
+/// assert!(!is_valid::<u8>(b"-1"));
+/// ```
+#[inline(always)]
+pub fn is_valid<I: FromRadix10Checked>(text: &[u8]) -> bool {
+    I::from_radix_10_checked(text).is_ok()
+}
+
+/// Parses an integer from the bytes of the pattern:
+/// - unsigned: `r"(0|[1-9][0-9]*)$"`
+/// - signed: `r"(0|-?[1-9][0-9]*)$"`
+///
+/// Only the canonical encoding of each integer is accepted: no '+', no redundant leading zeros and
+/// no "-0". This is the integer grammar of JSON, bencode and most wire protocols.
+#[inline(always)]
+pub fn atoi_canonical<I: FromRadix10Checked>(text: &[u8]) -> Result<I, ()> {
+    atoi_with(text, &ParseOptions::CANONICAL)
+}
+
+/// Parses an integer with the grammar described by `options`.
+///
+/// With [`ParseOptions::STD`] this is the same as [`atoi`]. Pass a `const` [`ParseOptions`] so
+/// that unused rules are compiled out.
+#[inline(always)]
+pub fn atoi_with<I: FromRadix10Checked>(text: &[u8], options: &ParseOptions) -> Result<I, ()> {
+    if text.is_empty() {
+        if options.empty_is_zero {
+            return I::from_radix_10_checked(b"0");
+        }
+        return error::<I>();
+    }
+    let digits = match text[0] {
+        b'+' if !options.plus_sign => return error::<I>(),
+        b'+' | b'-' => &text[1..],
+        _ => text,
+    };
+    if digits.len() > options.max_digits {
+        return error::<I>();
+    }
+    if !digits.is_empty() && digits[0] == b'0' {
+        if !options.leading_zeros && digits.len() > 1 {
+            return error::<I>();
+        }
+        if !options.negative_zero && text[0] == b'-' && is_all_zeros(digits) {
+            return error::<I>();
+        }
+    }
+    I::from_radix_10_checked(text)
+}
+
 /// Parses an integer from the bytes of the pattern:
 /// - unsigned: `r"\+?[0-9]+$"`
 /// - signed: `r"(\+|-)?[0-9]+$"`
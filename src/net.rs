@@ -0,0 +1,284 @@
+//! Parsers for network addresses.
+
+use crate::atoi_no_plus;
+use crate::core::*;
+use crate::simd::dispatch_sse41;
+
+/// Dispatches to the SSE4.1 kernel when available, like [`crate::parse_16`].
+#[inline(always)]
+fn parse_ipv4_16(buf: &[u8; 16], len: usize) -> Result<[u8; 4], ()> {
+    dispatch_sse41!(parse_ipv4(buf, len))
+}
+
+/// Parses a dotted-quad IPv4 address, e.g. `192.168.0.1`, into its octets.
+///
+/// The accepted syntax is the exact same as `std::net::Ipv4Addr::from_str`: four decimal octets
+/// of at most 255, without leading zeros.
+///
+/// ```
+/// use fast_atoi::net::parse_ipv4;
+///
+/// assert_eq!(parse_ipv4(b"192.168.0.1"), Ok([192, 168, 0, 1]));
+/// assert!(parse_ipv4(b"192.168.0.01").is_err());
+/// assert!(parse_ipv4(b"192.168.0.256").is_err());
+/// ```
+#[inline]
+pub fn parse_ipv4(s: &[u8]) -> Result<[u8; 4], ()> {
+    if s.len() < 7 || s.len() > 15 {
+        return error::<[u8; 4]>();
+    }
+    let mut buf = [0u8; 16];
+    buf[..s.len()].copy_from_slice(s);
+    parse_ipv4_16(&buf, s.len())
+}
+
+/// Parses an IPv4 address followed by a port, e.g. `192.168.0.1:8080`.
+///
+/// The accepted syntax is the exact same as `std::net::SocketAddrV4::from_str`. The port may
+/// have leading zeros.
+///
+/// ```
+/// use fast_atoi::net::parse_ipv4_port;
+///
+/// assert_eq!(parse_ipv4_port(b"10.0.0.1:443"), Ok(([10, 0, 0, 1], 443)));
+/// ```
+#[inline]
+pub fn parse_ipv4_port(s: &[u8]) -> Result<([u8; 4], u16), ()> {
+    // The address has at most 15 bytes, so the colon is found within the first 16.
+    let colon = match s.iter().take(16).position(|&b| b == b':') {
+        Some(i) => i,
+        None => return error::<([u8; 4], u16)>(),
+    };
+    let ip = parse_ipv4(&s[..colon])?;
+    let port = atoi_no_plus::<u16>(&s[colon + 1..])?;
+    Ok((ip, port))
+}
+
+/// Reads colon-separated groups into `groups`, the way `std` does. Returns the number of groups
+/// read and whether the last two came from an embedded IPv4 address.
+///
+/// An embedded IPv4 address always ends the address, so it is parsed from all of the remaining
+/// input. On a group that can't be read, `s` is left before its separator.
+#[inline]
+fn read_groups(s: &mut &[u8], groups: &mut [u16], err: &mut u64) -> (usize, bool) {
+    let limit = groups.len();
+    for i in 0..limit {
+        let mut p = *s;
+        if i > 0 {
+            match p {
+                [b':', rest @ ..] => p = rest,
+                _ => return (i, false),
+            }
+        }
+        let n = p
+            .iter()
+            .take(5)
+            .take_while(|b| b.is_ascii_hexdigit())
+            .count();
+        if i + 1 < limit && p.get(n) == Some(&b'.') {
+            return match parse_ipv4(p) {
+                Ok([a, b, c, d]) => {
+                    groups[i] = u16::from_be_bytes([a, b]);
+                    groups[i + 1] = u16::from_be_bytes([c, d]);
+                    *s = &[];
+                    (i + 2, true)
+                }
+                Err(()) => (i, false),
+            };
+        }
+        if n == 0 || n > 4 {
+            return (i, false);
+        }
+        let mut digits = [b'0'; 4];
+        digits[4 - n..].copy_from_slice(&p[..n]);
+        groups[i] = parse_hex_4(&mut &digits[..], err) as u16;
+        *s = &p[n..];
+    }
+    (limit, false)
+}
+
+/// Parses an IPv6 address, e.g. `2001:db8::8a2e:370:7334` or `::ffff:192.0.2.1`, into its eight
+/// 16-bit groups.
+///
+/// The accepted syntax is the exact same as `std::net::Ipv6Addr::from_str`: groups of 1 to 4 hex
+/// digits in either case, at most one `::`, and an optional embedded IPv4 address at the end.
+///
+/// ```
+/// use fast_atoi::net::parse_ipv6;
+///
+/// assert_eq!(parse_ipv6(b"2001:db8::1"), Ok([0x2001, 0xdb8, 0, 0, 0, 0, 0, 1]));
+/// assert_eq!(parse_ipv6(b"::ffff:1.2.3.4"), Ok([0, 0, 0, 0, 0, 0xffff, 0x102, 0x304]));
+/// assert!(parse_ipv6(b"1::2::3").is_err());
+/// ```
+#[inline]
+pub fn parse_ipv6(mut s: &[u8]) -> Result<[u16; 8], ()> {
+    let mut err = 0;
+    let mut groups = [0u16; 8];
+    let (head, head_ipv4) = read_groups(&mut s, &mut groups, &mut err);
+    if head == 8 {
+        return match (err, s.is_empty()) {
+            (0, true) => Ok(groups),
+            _ => error::<[u16; 8]>(),
+        };
+    }
+    // An embedded IPv4 address must be at the very end.
+    if head_ipv4 {
+        return error::<[u16; 8]>();
+    }
+    match s {
+        [b':', b':', rest @ ..] => s = rest,
+        _ => return error::<[u16; 8]>(),
+    }
+    // `::` stands for at least one group of zeros.
+    let mut tail = [0u16; 7];
+    let (tail_len, _) = read_groups(&mut s, &mut tail[..7 - head], &mut err);
+    if err != 0 || !s.is_empty() {
+        return error::<[u16; 8]>();
+    }
+    groups[8 - tail_len..].copy_from_slice(&tail[..tail_len]);
+    Ok(groups)
+}
+
+/// Parses a dotted-quad IPv4 address. See [`parse_ipv4`].
+#[cfg(feature = "std")]
+#[inline]
+pub fn parse_ipv4_addr(s: &[u8]) -> Result<std::net::Ipv4Addr, ()> {
+    parse_ipv4(s).map(std::net::Ipv4Addr::from)
+}
+
+/// Parses an IPv4 address followed by a port. See [`parse_ipv4_port`].
+#[cfg(feature = "std")]
+#[inline]
+pub fn parse_socket_addr_v4(s: &[u8]) -> Result<std::net::SocketAddrV4, ()> {
+    parse_ipv4_port(s).map(|(ip, port)| std::net::SocketAddrV4::new(ip.into(), port))
+}
+
+/// Parses an IPv6 address. See [`parse_ipv6`].
+#[cfg(feature = "std")]
+#[inline]
+pub fn parse_ipv6_addr(s: &[u8]) -> Result<std::net::Ipv6Addr, ()> {
+    parse_ipv6(s).map(std::net::Ipv6Addr::from)
+}
+
+/// The byte layout of a hardware address notation.
+struct HwLayout {
+    /// The offset of each pair of hex digits.
+    pairs: [usize; 8],
+    /// The separator bytes, as masks over the input padded to 24 bytes.
+    mask_lo: u128,
+    mask_hi: u64,
+}
+
+impl HwLayout {
+    const fn new(len: usize, group: usize) -> Self {
+        let mut layout = Self {
+            pairs: [0; 8],
+            mask_lo: 0,
+            mask_hi: 0,
+        };
+        let (mut i, mut pair) = (0, 0);
+        while i < len {
+            if (i + 1) % (2 * group + 1) == 0 {
+                if i < 16 {
+                    layout.mask_lo |= 0xff << (8 * i);
+                } else {
+                    layout.mask_hi |= 0xff << (8 * (i - 16));
+                }
+                i += 1;
+            } else {
+                layout.pairs[pair] = i;
+                pair += 1;
+                i += 2;
+            }
+        }
+        layout
+    }
+}
+
+const MAC_COLON: HwLayout = HwLayout::new(17, 1);
+const MAC_DOT: HwLayout = HwLayout::new(14, 2);
+const EUI64_COLON: HwLayout = HwLayout::new(23, 1);
+const EUI64_DOT: HwLayout = HwLayout::new(19, 2);
+
+/// Parses `N` hex pairs laid out as `layout`, all separated by `sep`.
+#[inline(always)]
+fn parse_hw<const N: usize>(s: &[u8], layout: &HwLayout, sep: u8) -> Result<[u8; N], ()> {
+    let mut buf = [0u8; 24];
+    buf[..s.len()].copy_from_slice(s);
+    let lo = u128::from_le_bytes(buf[..16].try_into().unwrap());
+    let hi = u64::from_le_bytes(buf[16..].try_into().unwrap());
+    let seps = ((lo & layout.mask_lo) ^ (layout.mask_lo & (sep as u128 * (u128::MAX / 0xff))))
+        | ((hi & layout.mask_hi) ^ (layout.mask_hi & (sep as u64 * (u64::MAX / 0xff)))) as u128;
+    let mut err = (seps != 0) as u64;
+
+    let mut digits = [b'0'; 16];
+    for (i, &at) in layout.pairs[..N].iter().enumerate() {
+        digits[2 * i..2 * i + 2].copy_from_slice(&buf[at..at + 2]);
+    }
+    let mut d = &digits[..];
+    let hi = parse_hex_8(&mut d, &mut err);
+    let lo = parse_hex_8(&mut d, &mut err);
+    let mut out = [0u8; N];
+    out.copy_from_slice(&(hi << 32 | lo).to_be_bytes()[..N]);
+    match err {
+        0 => Ok(out),
+        _ => error::<[u8; N]>(),
+    }
+}
+
+/// Parses a MAC (EUI-48) address in any of the common notations:
+/// - `aa:bb:cc:dd:ee:ff`
+/// - `aa-bb-cc-dd-ee-ff`
+/// - `aabb.ccdd.eeff` (Cisco)
+///
+/// Hex digits may be either case. All separators must be the same.
+///
+/// ```
+/// use fast_atoi::net::parse_mac;
+///
+/// let mac = [0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e];
+/// assert_eq!(parse_mac(b"00:1a:2b:3c:4d:5e"), Ok(mac));
+/// assert_eq!(parse_mac(b"00-1A-2B-3C-4D-5E"), Ok(mac));
+/// assert_eq!(parse_mac(b"001a.2b3c.4d5e"), Ok(mac));
+/// assert!(parse_mac(b"00:1a-2b:3c:4d:5e").is_err());
+/// ```
+#[inline]
+pub fn parse_mac(s: &[u8]) -> Result<[u8; 6], ()> {
+    match s.len() {
+        17 => parse_hw(s, &MAC_COLON, colon_or_hyphen(s)),
+        14 => parse_hw(s, &MAC_DOT, b'.'),
+        _ => error::<[u8; 6]>(),
+    }
+}
+
+/// Parses an EUI-64 address in any of the common notations:
+/// - `aa:bb:cc:dd:ee:ff:00:11`
+/// - `aa-bb-cc-dd-ee-ff-00-11`
+/// - `aabb.ccdd.eeff.0011`
+///
+/// Hex digits may be either case. All separators must be the same.
+///
+/// ```
+/// use fast_atoi::net::parse_eui64;
+///
+/// let eui = [0x02, 0x1a, 0x2b, 0xff, 0xfe, 0x3c, 0x4d, 0x5e];
+/// assert_eq!(parse_eui64(b"02:1a:2b:ff:fe:3c:4d:5e"), Ok(eui));
+/// assert_eq!(parse_eui64(b"021a.2bff.fe3c.4d5e"), Ok(eui));
+/// ```
+#[inline]
+pub fn parse_eui64(s: &[u8]) -> Result<[u8; 8], ()> {
+    match s.len() {
+        23 => parse_hw(s, &EUI64_COLON, colon_or_hyphen(s)),
+        19 => parse_hw(s, &EUI64_DOT, b'.'),
+        _ => error::<[u8; 8]>(),
+    }
+}
+
+/// Returns the separator of a colon or hyphen separated address, or a byte that fails the check.
+#[inline(always)]
+fn colon_or_hyphen(s: &[u8]) -> u8 {
+    match s[2] {
+        b'-' => b'-',
+        _ => b':',
+    }
+}
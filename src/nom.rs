@@ -0,0 +1,87 @@
+//! [`nom`](https://docs.rs/nom/7) combinators, drop-in replacements for `nom::character::complete`
+//! and `nom::character::streaming` integer parsers.
+//!
+//! Unsigned parsers accept `[0-9]+` and signed parsers `(\+|-)?[0-9]+`, the longest such prefix of
+//! the input. A missing or out of range number is an [`ErrorKind::Digit`] error, as in nom. The
+//! combinators at the root of this module are the complete ones.
+//!
+//! ```
+//! use fast_atoi::nom::{complete, streaming};
+//! use nom::{Err, Needed};
+//!
+//! assert_eq!(complete::u64::<_, ()>(&b"1234,"[..]), Ok((&b","[..], 1234)));
+//! assert_eq!(complete::i32::<_, ()>("-12"), Ok(("", -12)));
+//! assert_eq!(streaming::u64::<_, ()>(&b"1234"[..]), Err(Err::Incomplete(Needed::new(1))));
+//! ```
+
+use crate::core::count_digits;
+use crate::FromRadix10Checked;
+use ::nom::error::{ErrorKind, ParseError};
+use ::nom::{AsBytes, Err, IResult, Needed, Slice};
+use core::ops::RangeFrom;
+
+pub use self::complete::*;
+
+#[inline]
+fn parse_prefix<I, T, E>(input: T, signed: bool, streaming: bool) -> IResult<T, I, E>
+where
+    I: FromRadix10Checked,
+    T: AsBytes + Slice<RangeFrom<usize>>,
+    E: ParseError<T>,
+{
+    let s = input.as_bytes();
+    let sign = (signed && matches!(s.first(), Some(b'+' | b'-'))) as usize;
+    let len = sign + count_digits(&s[sign..]);
+    // Unsigned types also accept '+', but it was excluded from the prefix above.
+    match (len > sign, I::from_radix_10_checked(&s[..len])) {
+        // More digits could follow. A prefix that already overflows is an error, as in nom.
+        (false, _) | (true, Ok(_)) if streaming && len == s.len() => {
+            Err(Err::Incomplete(Needed::new(1)))
+        }
+        (true, Ok(x)) => Ok((input.slice(len..), x)),
+        _ => Err(Err::Error(E::from_error_kind(input, ErrorKind::Digit))),
+    }
+}
+
+macro_rules! impl_nom {
+    ($streaming:literal, $($name:ident, $signed:literal;)*) => {
+        $(
+            #[doc = concat!("Parses a `", stringify!($name), "` from the start of the input.")]
+            #[inline]
+            pub fn $name<T, E>(input: T) -> IResult<T, $name, E>
+            where
+                T: AsBytes + Slice<RangeFrom<usize>>,
+                E: ParseError<T>,
+            {
+                super::parse_prefix(input, $signed, $streaming)
+            }
+        )*
+    };
+}
+
+/// Combinators for input that is known to be complete.
+pub mod complete {
+    use ::nom::error::ParseError;
+    use ::nom::{AsBytes, IResult, Slice};
+    use core::ops::RangeFrom;
+
+    impl_nom!(
+        false,
+        u8, false; u16, false; u32, false; u64, false; u128, false;
+        i8, true; i16, true; i32, true; i64, true; i128, true;
+    );
+}
+
+/// Combinators for partial input. A number that reaches the end of the input might continue, so
+/// it is [`Err::Incomplete`] unless it is already out of range.
+pub mod streaming {
+    use ::nom::error::ParseError;
+    use ::nom::{AsBytes, IResult, Slice};
+    use core::ops::RangeFrom;
+
+    impl_nom!(
+        true,
+        u8, false; u16, false; u32, false; u64, false; u128, false;
+        i8, true; i16, true; i32, true; i64, true; i128, true;
+    );
+}
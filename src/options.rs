@@ -0,0 +1,100 @@
+/// Grammar knobs for [`crate::atoi_with`].
+///
+/// All setters are `const fn`, so options are meant to be built once as a `const` and passed by
+/// reference. Since [`crate::atoi_with`] is always inlined, the checks for a `const` options value
+/// fold away and only the enabled rules cost anything at runtime.
+///
+/// ```
+/// use fast_atoi::*;
+///
+/// const STRICT: ParseOptions = ParseOptions::new()
+///     .plus_sign(false)
+///     .leading_zeros(false)
+///     .max_digits(10);
+///
+/// assert_eq!(atoi_with::<u32>(b"42", &STRICT), Ok(42));
+/// assert!(atoi_with::<u32>(b"+42", &STRICT).is_err());
+/// assert!(atoi_with::<u32>(b"042", &STRICT).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    pub(crate) plus_sign: bool,
+    pub(crate) leading_zeros: bool,
+    pub(crate) max_digits: usize,
+    pub(crate) empty_is_zero: bool,
+    pub(crate) negative_zero: bool,
+}
+
+impl ParseOptions {
+    /// The same grammar as [`crate::atoi`] and `std::str::FromStr`.
+    pub const STD: Self = Self::new();
+
+    /// The same grammar as [`crate::atoi_no_plus`].
+    pub const NO_PLUS: Self = Self::new().plus_sign(false);
+
+    /// The same grammar as [`crate::atoi_canonical`]: every integer has exactly one accepted
+    /// encoding, so '+', redundant leading zeros and "-0" are rejected.
+    pub const CANONICAL: Self = Self::new()
+        .plus_sign(false)
+        .leading_zeros(false)
+        .negative_zero(false);
+
+    /// Returns options matching `std::str::FromStr`:
+    /// a leading '+' is allowed, leading zeros are allowed, "-0" is allowed, any number of digits is
+    /// allowed, and empty input is an error.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            plus_sign: true,
+            leading_zeros: true,
+            max_digits: usize::MAX,
+            empty_is_zero: false,
+            negative_zero: true,
+        }
+    }
+
+    /// Whether a single leading '+' is accepted. Default: `true`.
+    #[inline]
+    pub const fn plus_sign(mut self, allow: bool) -> Self {
+        self.plus_sign = allow;
+        self
+    }
+
+    /// Whether redundant leading zeros (e.g. "007") are accepted. A lone "0" is always accepted.
+    /// Default: `true`.
+    #[inline]
+    pub const fn leading_zeros(mut self, allow: bool) -> Self {
+        self.leading_zeros = allow;
+        self
+    }
+
+    /// The maximum number of digits accepted, not counting the sign but counting any leading zeros.
+    /// Default: `usize::MAX`.
+    #[inline]
+    pub const fn max_digits(mut self, max: usize) -> Self {
+        self.max_digits = max;
+        self
+    }
+
+    /// Whether a negative zero (e.g. "-0" or "-000") is accepted for signed types. Default: `true`.
+    #[inline]
+    pub const fn negative_zero(mut self, allow: bool) -> Self {
+        self.negative_zero = allow;
+        self
+    }
+
+    /// Whether empty input parses as `0` instead of an error. A lone sign is still an error.
+    /// Default: `false`.
+    #[inline]
+    pub const fn empty_is_zero(mut self, allow: bool) -> Self {
+        self.empty_is_zero = allow;
+        self
+    }
+}
+
+impl Default for ParseOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,182 @@
+use crate::simd::dispatch_sse2;
+use crate::FromRadix10Checked;
+use std::io::{self, BufRead};
+
+#[inline(always)]
+fn whitespace_16(s: &[u8]) -> u32 {
+    dispatch_sse2!(whitespace_16(s))
+}
+
+/// Returns the number of leading bytes of `s` that are (with `ws`) or are not (without) ASCII
+/// whitespace, checking 16 bytes at a time.
+#[inline]
+fn count_while(s: &[u8], ws: bool) -> usize {
+    let flip = if ws { 0xffff } else { 0 };
+    let mut n = 0;
+    while s.len() - n >= 16 {
+        let stop = whitespace_16(&s[n..]) ^ flip;
+        if stop != 0 {
+            return n + stop.trailing_zeros() as usize;
+        }
+        n += 16;
+    }
+    n + s[n..]
+        .iter()
+        .take_while(|b| b.is_ascii_whitespace() == ws)
+        .count()
+}
+
+#[cold]
+fn invalid<T>() -> io::Result<T> {
+    Err(io::ErrorKind::InvalidData.into())
+}
+
+/// A token that was split across buffer refills, with its leading zeros stripped so that it never
+/// needs more than a sign and the 39 digits of the longest type.
+struct Spill {
+    buf: [u8; 40],
+    len: usize,
+    too_long: bool,
+}
+
+impl Spill {
+    #[cold]
+    fn new() -> Self {
+        Self {
+            buf: [0; 40],
+            len: 0,
+            too_long: false,
+        }
+    }
+
+    #[cold]
+    fn push(&mut self, s: &[u8]) {
+        for &b in s {
+            let sign = (self.len > 0 && matches!(self.buf[0], b'+' | b'-')) as usize;
+            if self.len == sign + 1 && self.buf[sign] == b'0' && b.is_ascii_digit() {
+                self.buf[sign] = b;
+            } else if self.len < self.buf.len() {
+                self.buf[self.len] = b;
+                self.len += 1;
+            } else {
+                self.too_long = true;
+            }
+        }
+    }
+
+    #[cold]
+    fn parse<T: FromRadix10Checked>(&self) -> io::Result<T> {
+        match (
+            self.too_long,
+            T::from_radix_10_checked(&self.buf[..self.len]),
+        ) {
+            (false, Ok(x)) => Ok(x),
+            _ => invalid::<T>(),
+        }
+    }
+}
+
+/// Reads whitespace-separated integers from a [`BufRead`], such as a locked stdin or a
+/// `BufReader<File>`.
+///
+/// Each token is parsed with [`crate::atoi`] straight out of the reader's buffer. Only a token
+/// that is split across two refills is copied, and never more than 40 bytes of it.
+///
+/// ```
+/// use fast_atoi::Scanner;
+///
+/// let mut scanner = Scanner::new(&b"3\n-1 4\t1"[..]);
+/// assert_eq!(scanner.next::<u8>().unwrap().unwrap(), 3);
+/// assert_eq!(scanner.next::<i32>().unwrap().unwrap(), -1);
+/// assert_eq!(scanner.next::<u64>().unwrap().unwrap(), 4);
+/// assert_eq!(scanner.next::<u64>().unwrap().unwrap(), 1);
+/// assert!(scanner.next::<u64>().is_none());
+/// ```
+pub struct Scanner<R> {
+    reader: R,
+}
+
+impl<R: BufRead> Scanner<R> {
+    /// Creates a scanner that reads tokens from `reader`.
+    #[inline]
+    pub fn new(reader: R) -> Self {
+        Self { reader }
+    }
+
+    /// Returns the underlying reader, positioned just after the last token read.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    #[inline]
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        loop {
+            match self.reader.fill_buf() {
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                // Re-borrowed to work around the borrow checker's handling of loops.
+                Ok(_) => return self.reader.fill_buf(),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Reads the next whitespace-separated token and parses it as a `T`.
+    ///
+    /// Returns `None` at the end of the input. A token that is not a valid `T` is consumed and
+    /// returned as an [`io::ErrorKind::InvalidData`] error, so scanning can continue after it.
+    #[inline]
+    #[allow(clippy::should_implement_trait)]
+    pub fn next<T: FromRadix10Checked>(&mut self) -> Option<io::Result<T>> {
+        loop {
+            let buf = match self.fill_buf() {
+                Ok([]) => return None,
+                Ok(buf) => buf,
+                Err(e) => return Some(Err(e)),
+            };
+            let ws = count_while(buf, true);
+            let len = buf.len();
+            self.reader.consume(ws);
+            if ws < len {
+                break;
+            }
+        }
+
+        let buf = match self.fill_buf() {
+            Ok(buf) => buf,
+            Err(e) => return Some(Err(e)),
+        };
+        let n = count_while(buf, false);
+        if n < buf.len() {
+            let res = match T::from_radix_10_checked(&buf[..n]) {
+                Ok(x) => Ok(x),
+                Err(()) => invalid::<T>(),
+            };
+            self.reader.consume(n);
+            return Some(res);
+        }
+        Some(self.next_split(n))
+    }
+
+    /// Finishes reading a token that reaches the end of the buffer, of which `n` bytes are
+    /// available.
+    #[cold]
+    fn next_split<T: FromRadix10Checked>(&mut self, mut n: usize) -> io::Result<T> {
+        let mut spill = Spill::new();
+        loop {
+            let buf = self.fill_buf()?;
+            spill.push(&buf[..n]);
+            let len = buf.len();
+            self.reader.consume(n);
+            if n < len {
+                break;
+            }
+            let buf = self.fill_buf()?;
+            if buf.is_empty() {
+                break;
+            }
+            n = count_while(buf, false);
+        }
+        spill.parse()
+    }
+}
@@ -0,0 +1,93 @@
+use crate::atoi_canonical;
+use crate::core::{count_digits, error};
+
+/// A semantic version, borrowing its pre-release and build metadata from the input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SemVer<'a> {
+    /// The major version, incremented for incompatible changes.
+    pub major: u64,
+    /// The minor version, incremented for compatible additions.
+    pub minor: u64,
+    /// The patch version, incremented for compatible fixes.
+    pub patch: u64,
+    /// The pre-release identifiers after '-', without the '-'. Empty if there are none.
+    pub pre: &'a [u8],
+    /// The build metadata after '+', without the '+'. Empty if there is none.
+    pub build: &'a [u8],
+}
+
+/// Parses a numeric identifier, `0|[1-9][0-9]*`, from the start of `s`.
+#[inline]
+fn numeric(s: &mut &[u8]) -> Result<u64, ()> {
+    let (digits, rest) = s.split_at(count_digits(s));
+    *s = rest;
+    atoi_canonical(digits)
+}
+
+/// Checks that `s` is a non-empty dot-separated list of non-empty `[0-9A-Za-z-]+` identifiers.
+/// With `is_pre`, purely numeric identifiers must not have leading zeros.
+#[inline]
+fn check_identifiers(s: &[u8], is_pre: bool) -> Result<(), ()> {
+    for id in s.split(|&b| b == b'.') {
+        if id.is_empty() || !id.iter().all(|&b| b.is_ascii_alphanumeric() || b == b'-') {
+            return error::<()>();
+        }
+        if is_pre && id.len() > 1 && id[0] == b'0' && count_digits(id) == id.len() {
+            return error::<()>();
+        }
+    }
+    Ok(())
+}
+
+/// Parses a SemVer 2.0 version string, e.g. `1.2.3`, `1.2.3-rc.1+build.5` or `6.18.44-fc-v130`.
+///
+/// The grammar is the one from <https://semver.org>: major, minor and patch are
+/// `0|[1-9][0-9]*` and must fit in a `u64`, pre-release and build identifiers are
+/// `[0-9A-Za-z-]+`, and numeric pre-release identifiers must not have leading zeros.
+///
+/// ```
+/// use fast_atoi::*;
+///
+/// let v = parse_semver(b"1.2.3-rc.1+build.5").unwrap();
+/// assert_eq!((v.major, v.minor, v.patch), (1, 2, 3));
+/// assert_eq!(v.pre, b"rc.1");
+/// assert_eq!(v.build, b"build.5");
+/// assert!(parse_semver(b"1.02.3").is_err());
+/// ```
+#[inline]
+pub fn parse_semver(mut s: &[u8]) -> Result<SemVer<'_>, ()> {
+    let major = numeric(&mut s)?;
+    if s.first() != Some(&b'.') {
+        return error::<SemVer>();
+    }
+    s = &s[1..];
+    let minor = numeric(&mut s)?;
+    if s.first() != Some(&b'.') {
+        return error::<SemVer>();
+    }
+    s = &s[1..];
+    let patch = numeric(&mut s)?;
+
+    let (mut pre, mut build): (&[u8], &[u8]) = (&[], &[]);
+    if s.first() == Some(&b'-') {
+        let end = s.iter().position(|&b| b == b'+').unwrap_or(s.len());
+        pre = &s[1..end];
+        check_identifiers(pre, true)?;
+        s = &s[end..];
+    }
+    if s.first() == Some(&b'+') {
+        build = &s[1..];
+        check_identifiers(build, false)?;
+        s = &[];
+    }
+    if !s.is_empty() {
+        return error::<SemVer>();
+    }
+    Ok(SemVer {
+        major,
+        minor,
+        patch,
+        pre,
+        build,
+    })
+}
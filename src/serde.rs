@@ -0,0 +1,125 @@
+//! Serde support for integers encoded as strings, e.g. 64-bit IDs in JSON.
+//!
+//! Use [`str`](mod@str) as a field attribute or [`StrInt`] as a field type:
+//!
+//! ```
+//! use fast_atoi::serde::StrInt;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Message {
+//!     #[serde(with = "fast_atoi::serde::str")]
+//!     id: u64,
+//!     parent: StrInt<i64>,
+//! }
+//!
+//! let json = r#"{"id":"1234567890123456789","parent":"-1"}"#;
+//! let message: Message = serde_json::from_str(json).unwrap();
+//! assert_eq!(message.id, 1234567890123456789);
+//! assert_eq!(message.parent, StrInt(-1));
+//! assert_eq!(serde_json::to_string(&message).unwrap(), json);
+//! ```
+
+use crate::{FromRadix10Checked, ToRadix10};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes an integer as a decimal string and deserializes it from a string or bytes with
+/// [`crate::atoi`], for use with `#[serde(with = "fast_atoi::serde::str")]`.
+pub mod str {
+    use crate::core::is_integer_syntax;
+    use crate::{FromRadix10Checked, ToRadix10};
+    use ::serde::de::{self, Unexpected, Visitor};
+    use ::serde::{Deserializer, Serializer};
+    use core::fmt;
+    use core::marker::PhantomData;
+
+    struct StrIntVisitor<T>(PhantomData<T>);
+
+    impl<T: FromRadix10Checked> StrIntVisitor<T> {
+        #[inline]
+        fn parse<E: de::Error>(&self, v: &[u8], unexpected: Unexpected<'_>) -> Result<T, E> {
+            match T::from_radix_10_checked(v) {
+                Ok(x) => Ok(x),
+                Err(()) if is_integer_syntax(v, b"+-") => {
+                    Err(E::invalid_value(unexpected, &OutOfRange::<T>(PhantomData)))
+                }
+                Err(()) => Err(E::invalid_value(unexpected, self)),
+            }
+        }
+    }
+
+    impl<'de, T: FromRadix10Checked> Visitor<'de> for StrIntVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "a string containing a {} integer",
+                core::any::type_name::<T>()
+            )
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+            self.parse(v.as_bytes(), Unexpected::Str(v))
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+            self.parse(v, Unexpected::Bytes(v))
+        }
+    }
+
+    struct OutOfRange<T>(PhantomData<T>);
+
+    impl<T> de::Expected for OutOfRange<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "an integer in the range of {}",
+                core::any::type_name::<T>()
+            )
+        }
+    }
+
+    /// Serializes `value` as a decimal string.
+    #[inline]
+    pub fn serialize<T: ToRadix10 + Copy, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut buf = [0u8; 40];
+        let digits = value.to_radix_10(&mut buf);
+        // Digits and '-' are ASCII.
+        serializer.serialize_str(unsafe { core::str::from_utf8_unchecked(digits) })
+    }
+
+    /// Deserializes an integer from a string or bytes with the grammar of [`crate::atoi`].
+    ///
+    /// A value with the syntax of an integer that does not fit in `T` is reported as out of range;
+    /// anything else is reported as an invalid value.
+    #[inline]
+    pub fn deserialize<'de, T: FromRadix10Checked, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        deserializer.deserialize_str(StrIntVisitor(PhantomData))
+    }
+}
+
+/// An integer that is serialized as a decimal string and deserialized from a string or bytes.
+///
+/// See [`str`](mod@str) for the format.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StrInt<T>(pub T);
+
+impl<T: ToRadix10 + Copy> Serialize for StrInt<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self::str::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, T: FromRadix10Checked> Deserialize<'de> for StrInt<T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        self::str::deserialize(deserializer).map(StrInt)
+    }
+}
@@ -127,8 +127,9 @@ macro_rules! impl_signed_radix_10 {
                 }
                 let mut is_err = 0;
                 let res = match s.len() {
-                    1 => sign * parse_1(&mut s, &mut is_err) as $type,
-                    2 => sign * parse_2(&mut s, &mut is_err) as $type,
+                    // Invalid digits can make the product overflow; they are reported by `is_err`.
+                    1 => (parse_1(&mut s, &mut is_err) as $type).wrapping_mul(sign),
+                    2 => (parse_2(&mut s, &mut is_err) as $type).wrapping_mul(sign),
                     3..=$max_digits => $parse_n(&mut s, &mut is_err, sign),
                     _ => {
                         strip_leading_zeros(&mut s, $max_digits);
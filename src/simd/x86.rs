@@ -0,0 +1,271 @@
+#![allow(dead_code)]
+
+use core::arch::x86_64::*;
+
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn simd_validate_and_sub(v: __m128i, is_err: &mut u64) -> __m128i {
+    let zero = _mm_set1_epi8(b'0' as i8);
+    let nine = _mm_set1_epi8(b'9' as i8);
+
+    let lt_zero = _mm_cmplt_epi8(v, zero);
+    let gt_nine = _mm_cmpgt_epi8(v, nine);
+    let bad = _mm_or_si128(lt_zero, gt_nine);
+
+    *is_err |= _mm_movemask_epi8(bad) as u64;
+    _mm_sub_epi8(v, zero)
+}
+
+/// Rust translation of <https://github.com/WojciechMula/toys/blob/master/conv_from_dec/parse.ssse3.cpp>.
+#[target_feature(enable = "sse4.1")]
+#[inline]
+pub(crate) unsafe fn parse_16(s: &mut &[u8], err: &mut u64) -> u64 {
+    debug_assert!(s.len() >= 16);
+    let src = _mm_loadu_si128(s.as_ptr() as *const __m128i);
+    *s = &s[16..];
+
+    let v = simd_validate_and_sub(src, err);
+    let mul_1_10 = _mm_setr_epi8(10, 1, 10, 1, 10, 1, 10, 1, 10, 1, 10, 1, 10, 1, 10, 1);
+    let mul_1_100 = _mm_setr_epi16(100, 1, 100, 1, 100, 1, 100, 1);
+    let mul_1_10000 = _mm_setr_epi16(10000, 1, 10000, 1, 10000, 1, 10000, 1);
+    let t1 = _mm_maddubs_epi16(v, mul_1_10);
+    let t2 = _mm_madd_epi16(t1, mul_1_100);
+    let t3 = _mm_packus_epi32(t2, t2);
+    let t4 = _mm_madd_epi16(t3, mul_1_10000);
+
+    let mut tmp = [0u8; 16];
+    _mm_storeu_si128(tmp.as_mut_ptr() as *mut __m128i, t4);
+    let hi = u32::from_le_bytes(tmp[0..4].try_into().unwrap()) as u64;
+    let lo = u32::from_le_bytes(tmp[4..8].try_into().unwrap()) as u64;
+
+    hi.wrapping_mul(100000000).wrapping_add(lo)
+}
+
+/// Returns the 8 decimal digits of `n`, which must be below 10^8, in the 16-bit lanes.
+///
+/// Rust translation of <https://github.com/miloyip/itoa-benchmark/blob/master/src/sse2.cpp>.
+#[inline(always)]
+unsafe fn digits_8(n: u64) -> __m128i {
+    let div_10000 = _mm_set1_epi32(0xd1b71759u32 as i32);
+    let mul_10000 = _mm_set1_epi32(10000);
+    // 10^3, 10^2, 10^1, 10^0 as reciprocals, then the shifts that finish each division
+    let div_powers = _mm_setr_epi16(8389, 5243, 13108, -32768, 8389, 5243, 13108, -32768);
+    let shift_powers = _mm_setr_epi16(128, 2048, 8192, -32768, 128, 2048, 8192, -32768);
+    let mul_10 = _mm_set1_epi16(10);
+
+    // abcd, efgh = abcdefgh divmod 10000
+    let abcdefgh = _mm_cvtsi32_si128(n as i32);
+    let abcd = _mm_srli_epi64(_mm_mul_epu32(abcdefgh, div_10000), 45);
+    let efgh = _mm_sub_epi32(abcdefgh, _mm_mul_epu32(abcd, mul_10000));
+    // [abcd * 4, efgh * 4, 0, ...], then broadcast into 4 lanes each
+    let v1 = _mm_slli_epi64(_mm_unpacklo_epi16(abcd, efgh), 2);
+    let v2 = _mm_unpacklo_epi16(v1, v1);
+    let v2 = _mm_unpacklo_epi32(v2, v2);
+    // [a, ab, abc, abcd, e, ef, efg, efgh]
+    let v4 = _mm_mulhi_epu16(_mm_mulhi_epu16(v2, div_powers), shift_powers);
+    // [0, a0, ab0, abc0, 0, e0, ef0, efg0]
+    let v6 = _mm_slli_epi64(_mm_mullo_epi16(v4, mul_10), 16);
+    // [a, b, c, d, e, f, g, h]
+    _mm_sub_epi16(v4, v6)
+}
+
+/// Writes `n`, which must be below 10^16, as 16 zero-padded digits.
+#[inline]
+pub(crate) unsafe fn write_16(s: &mut [u8], n: u64) {
+    debug_assert!(s.len() >= 16);
+    let hi = digits_8(n / 100_000_000);
+    let lo = digits_8(n % 100_000_000);
+    let v = _mm_add_epi8(_mm_packus_epi16(hi, lo), _mm_set1_epi8(b'0' as i8));
+    _mm_storeu_si128(s.as_mut_ptr() as *mut __m128i, v);
+}
+
+/// Returns a mask of the bytes among the first 16 of `s` that are ASCII whitespace, as defined by
+/// `u8::is_ascii_whitespace`.
+#[inline]
+pub(crate) unsafe fn whitespace_16(s: &[u8]) -> u32 {
+    debug_assert!(s.len() >= 16);
+    let v = _mm_loadu_si128(s.as_ptr() as *const __m128i);
+    let mut ws = _mm_cmpeq_epi8(v, _mm_set1_epi8(b' ' as i8));
+    for b in [b'\t', b'\n', b'\x0c', b'\r'] {
+        ws = _mm_or_si128(ws, _mm_cmpeq_epi8(v, _mm_set1_epi8(b as i8)));
+    }
+    _mm_movemask_epi8(ws) as u32
+}
+
+/// Narrows 16 UTF-16 code units into bytes, saturating every unit above 0xff to a non-digit.
+#[inline]
+pub(crate) unsafe fn narrow_16(src: &[u16], dst: &mut [u8]) {
+    debug_assert!(src.len() >= 16 && dst.len() >= 16);
+    let lo = _mm_loadu_si128(src.as_ptr() as *const __m128i);
+    let hi = _mm_loadu_si128(src.as_ptr().add(8) as *const __m128i);
+    // Units are treated as signed, so 0x8000..=0xffff become 0 and 0x0100..=0x7fff become 0xff.
+    let v = _mm_packus_epi16(lo, hi);
+    _mm_storeu_si128(dst.as_mut_ptr() as *mut __m128i, v);
+}
+
+/// `pshufb` controls for each of the 81 combinations of IPv4 octet lengths, indexed by
+/// `ipv4_shuffle_index`. Octet `i` is right-aligned into bytes `4 * i..4 * i + 3`, and byte
+/// `4 * i + 3` is zeroed.
+const IPV4_SHUFFLE: [[u8; 16]; 81] = ipv4_shuffle_table();
+
+#[inline(always)]
+const fn ipv4_shuffle_index(lens: [usize; 4]) -> usize {
+    27 * (lens[0] - 1) + 9 * (lens[1] - 1) + 3 * (lens[2] - 1) + (lens[3] - 1)
+}
+
+const fn ipv4_shuffle_table() -> [[u8; 16]; 81] {
+    let mut table = [[0x80u8; 16]; 81];
+    let mut i = 0;
+    while i < 81 {
+        let lens = [i / 27 + 1, i / 9 % 3 + 1, i / 3 % 3 + 1, i % 3 + 1];
+        let mut start = 0;
+        let mut octet = 0;
+        while octet < 4 {
+            let len = lens[octet];
+            let mut k = 0;
+            while k < len {
+                table[i][4 * octet + 3 - len + k] = (start + k) as u8;
+                k += 1;
+            }
+            start += len + 1;
+            octet += 1;
+        }
+        i += 1;
+    }
+    table
+}
+
+/// Parses a dotted-quad IPv4 address from the first `len` bytes of `buf`.
+/// The bytes past `len` must not be '.'.
+#[target_feature(enable = "sse4.1")]
+#[inline]
+pub(crate) unsafe fn parse_ipv4(buf: &[u8; 16], len: usize) -> Result<[u8; 4], ()> {
+    let v = _mm_loadu_si128(buf.as_ptr() as *const __m128i);
+    let in_bounds = (1u32 << len) - 1;
+    let dots = _mm_movemask_epi8(_mm_cmpeq_epi8(v, _mm_set1_epi8(b'.' as i8))) as u32;
+    let mut not_digits = 0;
+    let v = simd_validate_and_sub(v, &mut not_digits);
+    if dots.count_ones() != 3 || (not_digits as u32 & in_bounds) != dots {
+        return Err(());
+    }
+
+    let d0 = dots.trailing_zeros() as usize;
+    let d1 = (dots & (dots - 1)).trailing_zeros() as usize;
+    let d2 = 31 - dots.leading_zeros() as usize;
+    let lens = [d0, d1 - d0 - 1, d2 - d1 - 1, len - d2 - 1];
+    let starts = [0, d0 + 1, d1 + 1, d2 + 1];
+    let mut bad = 0;
+    for i in 0..4 {
+        bad |= (lens[i].wrapping_sub(1) > 2) as u64;
+        bad |= (lens[i] > 1 && buf[starts[i]] == b'0') as u64;
+    }
+    if bad != 0 {
+        return Err(());
+    }
+
+    let shuffle =
+        _mm_loadu_si128(IPV4_SHUFFLE[ipv4_shuffle_index(lens)].as_ptr() as *const __m128i);
+    let digits = _mm_shuffle_epi8(v, shuffle);
+    let mul = _mm_setr_epi8(100, 10, 1, 0, 100, 10, 1, 0, 100, 10, 1, 0, 100, 10, 1, 0);
+    let t1 = _mm_maddubs_epi16(digits, mul);
+    let octets = _mm_madd_epi16(t1, _mm_set1_epi16(1));
+    if _mm_movemask_epi8(_mm_cmpgt_epi32(octets, _mm_set1_epi32(255))) != 0 {
+        return Err(());
+    }
+    let packed = _mm_packus_epi16(_mm_packus_epi32(octets, octets), octets);
+    Ok((_mm_cvtsi128_si32(packed) as u32).to_le_bytes())
+}
+
+/// Converts 16 hex bytes (either case) into a `u64`, most significant digit first.
+#[target_feature(enable = "sse4.1")]
+#[inline]
+unsafe fn hex_16(v: __m128i, is_err: &mut u64) -> u64 {
+    let lower = _mm_or_si128(v, _mm_set1_epi8(0x20));
+    let digit = _mm_and_si128(
+        _mm_cmpgt_epi8(v, _mm_set1_epi8(b'0' as i8 - 1)),
+        _mm_cmplt_epi8(v, _mm_set1_epi8(b'9' as i8 + 1)),
+    );
+    let alpha = _mm_and_si128(
+        _mm_cmpgt_epi8(lower, _mm_set1_epi8(b'a' as i8 - 1)),
+        _mm_cmplt_epi8(lower, _mm_set1_epi8(b'f' as i8 + 1)),
+    );
+    *is_err |= (_mm_movemask_epi8(_mm_or_si128(digit, alpha)) ^ 0xffff) as u64;
+
+    let nibbles = _mm_blendv_epi8(
+        _mm_sub_epi8(lower, _mm_set1_epi8(b'a' as i8 - 10)),
+        _mm_sub_epi8(v, _mm_set1_epi8(b'0' as i8)),
+        digit,
+    );
+    let mul_1_16 = _mm_setr_epi8(16, 1, 16, 1, 16, 1, 16, 1, 16, 1, 16, 1, 16, 1, 16, 1);
+    let bytes = _mm_packus_epi16(_mm_maddubs_epi16(nibbles, mul_1_16), _mm_setzero_si128());
+    u64::from_be(_mm_cvtsi128_si64(bytes) as u64)
+}
+
+/// Parses 32 hex bytes into a `u128`.
+#[target_feature(enable = "sse4.1")]
+#[inline]
+pub(crate) unsafe fn parse_uuid_simple(s: &[u8]) -> Result<u128, ()> {
+    debug_assert!(s.len() >= 32);
+    let mut err = 0;
+    let hi = hex_16(_mm_loadu_si128(s.as_ptr() as *const __m128i), &mut err);
+    let lo = hex_16(
+        _mm_loadu_si128(s.as_ptr().add(16) as *const __m128i),
+        &mut err,
+    );
+    match err {
+        0 => Ok((hi as u128) << 64 | lo as u128),
+        _ => Err(()),
+    }
+}
+
+/// Parses a 36 byte hyphenated UUID into a `u128`.
+#[target_feature(enable = "sse4.1")]
+#[inline]
+pub(crate) unsafe fn parse_uuid_hyphenated(s: &[u8]) -> Result<u128, ()> {
+    debug_assert!(s.len() >= 36);
+    let p = s.as_ptr();
+    // The hyphens are at 8, 13, 18 and 23.
+    let a = _mm_loadu_si128(p as *const __m128i); // 0..16
+    let b = _mm_loadu_si128(p.add(2) as *const __m128i); // 2..18
+    let c = _mm_loadu_si128(p.add(16) as *const __m128i); // 16..32
+    let d = _mm_loadu_si128(p.add(20) as *const __m128i); // 20..36
+
+    let hyphen = _mm_set1_epi8(b'-' as i8);
+    let hyphens_a = _mm_movemask_epi8(_mm_cmpeq_epi8(a, hyphen)) & (1 << 8 | 1 << 13);
+    let hyphens_c = _mm_movemask_epi8(_mm_cmpeq_epi8(c, hyphen)) & (1 << 2 | 1 << 7);
+    if hyphens_a != (1 << 8 | 1 << 13) || hyphens_c != (1 << 2 | 1 << 7) {
+        return Err(());
+    }
+
+    // 0..8, 9..13, 14..18
+    let hi = _mm_or_si128(
+        _mm_shuffle_epi8(
+            a,
+            _mm_setr_epi8(0, 1, 2, 3, 4, 5, 6, 7, 9, 10, 11, 12, 14, 15, -1, -1),
+        ),
+        _mm_shuffle_epi8(
+            b,
+            _mm_setr_epi8(
+                -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, 14, 15,
+            ),
+        ),
+    );
+    // 19..23, 24..36
+    let lo = _mm_or_si128(
+        _mm_shuffle_epi8(
+            c,
+            _mm_setr_epi8(3, 4, 5, 6, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1),
+        ),
+        _mm_shuffle_epi8(
+            d,
+            _mm_setr_epi8(-1, -1, -1, -1, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15),
+        ),
+    );
+    let mut err = 0;
+    let hi = hex_16(hi, &mut err);
+    let lo = hex_16(lo, &mut err);
+    match err {
+        0 => Ok((hi as u128) << 64 | lo as u128),
+        _ => Err(()),
+    }
+}
@@ -0,0 +1,87 @@
+use crate::core::{scale_fraction, split_decimal};
+use crate::FromRadix10CheckedNoPlus;
+
+/// The reason a byte size could not be parsed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SizeError {
+    /// The input is not a valid size.
+    Invalid,
+    /// The input is a valid size, but does not fit in the target type.
+    Overflow,
+}
+
+impl core::fmt::Display for SizeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            SizeError::Invalid => "invalid size",
+            SizeError::Overflow => "size out of range",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SizeError {}
+
+/// Returns the multiplier of a suffix, with or without the trailing 'B'.
+#[inline]
+fn multiplier(suffix: &[u8]) -> Option<u128> {
+    let suffix = match suffix {
+        [rest @ .., b'B'] => rest,
+        _ => suffix,
+    };
+    let (prefix, iec) = match suffix {
+        [] => return Some(1),
+        [p] => (*p, false),
+        [p, b'i'] => (*p, true),
+        _ => return None,
+    };
+    let exp = match prefix {
+        b'k' if !iec => 1,
+        b'K' => 1,
+        b'M' => 2,
+        b'G' => 3,
+        b'T' => 4,
+        b'P' => 5,
+        b'E' => 6,
+        _ => return None,
+    };
+    Some(match iec {
+        true => 1 << (10 * exp),
+        false => 1000u128.pow(exp),
+    })
+}
+
+/// Parses a byte size such as `512MiB`, `4G`, `1.5TB` or `10k`.
+///
+/// A size is a number `[0-9]+(\.[0-9]+)?` followed by an optional suffix:
+/// - SI: `k` (or `K`), `M`, `G`, `T`, `P` and `E`, powers of 1000
+/// - IEC: `Ki`, `Mi`, `Gi`, `Ti`, `Pi` and `Ei`, powers of 1024
+///
+/// Either may be followed by `B`, and `B` alone means bytes. A fractional size is rounded down
+/// to a whole number of bytes. A size that does not fit in `T` is [`SizeError::Overflow`].
+///
+/// ```
+/// use fast_atoi::*;
+///
+/// assert_eq!(parse_size::<u64>(b"512MiB"), Ok(512 << 20));
+/// assert_eq!(parse_size::<u64>(b"4G"), Ok(4_000_000_000));
+/// assert_eq!(parse_size::<u64>(b"1.5TB"), Ok(1_500_000_000_000));
+/// assert_eq!(parse_size::<u64>(b"10k"), Ok(10_000));
+/// assert_eq!(parse_size::<u32>(b"4GiB"), Err(SizeError::Overflow));
+/// assert_eq!(parse_size::<u64>(b"4gb"), Err(SizeError::Invalid));
+/// ```
+#[inline]
+pub fn parse_size<T: TryFrom<u128>>(mut s: &[u8]) -> Result<T, SizeError> {
+    let (int, frac) = split_decimal(&mut s).map_err(|_| SizeError::Invalid)?;
+    if int.is_empty() {
+        return Err(SizeError::Invalid);
+    }
+    let mult = multiplier(s).ok_or(SizeError::Invalid)?;
+
+    let int = u128::from_radix_10_checked_no_plus(int).map_err(|_| SizeError::Overflow)?;
+    let total = int
+        .checked_mul(mult)
+        .and_then(|total| total.checked_add(scale_fraction(frac, mult)))
+        .ok_or(SizeError::Overflow)?;
+    T::try_from(total).map_err(|_| SizeError::Overflow)
+}
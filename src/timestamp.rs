@@ -0,0 +1,682 @@
+//! Parsers for timestamp layouts, built from the crate's fixed-width digit blocks.
+//!
+//! - [`parse_rfc3339`] parses `YYYY-MM-DDTHH:MM:SS[.fffffffff](Z|±HH:MM)`
+//! - [`parse_fix_timestamp`] parses `YYYYMMDD-HH:MM:SS[.sss]`
+//! - [`parse_http_date`] and [`parse_rfc2822`] parse HTTP and email dates
+//! - [`parse_yyyymmddhhmmss`], [`parse_yyyymmdd`], [`parse_hhmmss`] and [`parse_hhmmssmmm`]
+
+use crate::core::*;
+
+pub(crate) const NANOS_PER_SEC: i64 = 1_000_000_000;
+pub(crate) const SECS_PER_DAY: i64 = 86_400;
+
+#[inline]
+pub(crate) fn is_leap_year(year: u64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+#[inline]
+pub(crate) fn days_in_month(year: u64, month: u64) -> u64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days from 1970-01-01 to the proleptic Gregorian date.
+/// `month` and `day` must already be in range.
+///
+/// <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+#[inline]
+pub(crate) fn days_from_civil(year: u64, month: u64, day: u64) -> i64 {
+    let y = year as i64 - (month <= 2) as i64;
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Sets `err` if the date is not a real calendar date.
+#[inline]
+pub(crate) fn check_date(year: u64, month: u64, day: u64, err: &mut u64) {
+    *err |= (month.wrapping_sub(1) >= 12) as u64;
+    *err |= (day.wrapping_sub(1) >= days_in_month(year, month)) as u64;
+}
+
+/// Sets `err` if the time of day is out of range. A leap second (`60`) is allowed.
+#[inline]
+pub(crate) fn check_time(hour: u64, minute: u64, second: u64, err: &mut u64) {
+    *err |= (hour > 23 || minute > 59 || second > 60) as u64;
+}
+
+/// Parses 1 to 9 fraction digits as nanoseconds. Digits past the ninth are checked and truncated.
+#[inline]
+pub(crate) fn parse_nanos(s: &[u8], err: &mut u64) -> u64 {
+    let mut digits = [b'0'; 9];
+    let n = s.len().min(9);
+    digits[..n].copy_from_slice(&s[..n]);
+    *err |= (s.is_empty() || count_digits(&s[n..]) != s.len() - n) as u64;
+    let mut d = &digits[..];
+    let hi = parse_8(&mut d, err);
+    fold_1(&mut d, err, hi)
+}
+
+/// Combines days since the epoch, seconds into the day and nanoseconds into the second into
+/// nanoseconds since the Unix epoch. `secs` may be negative or past the end of the day.
+#[inline]
+pub(crate) fn to_epoch_nanos(days: i64, secs: i64, nanos: u64) -> Result<i64, ()> {
+    let ns = (days as i128 * SECS_PER_DAY as i128 + secs as i128) * NANOS_PER_SEC as i128;
+    i64::try_from(ns + nanos as i128).map_err(|_| ())
+}
+
+// The separators of "YYYY-MM-DDTHH:MM:SS" from byte 3 to byte 18, checked as one `u128`.
+// 'T' is masked with 0xdf so that 't' is also accepted.
+const SEP_MASK: u128 = u128::from_le_bytes([
+    0, 0xff, 0, 0, 0xff, 0, 0, 0xdf, 0, 0, 0xff, 0, 0, 0xff, 0, 0,
+]);
+const SEP: u128 = u128::from_le_bytes([
+    0, b'-', 0, 0, b'-', 0, 0, b'T', 0, 0, b':', 0, 0, b':', 0, 0,
+]);
+
+/// Parses an RFC 3339 timestamp of the form `YYYY-MM-DDTHH:MM:SS[.fffffffff](Z|±HH:MM)` into
+/// nanoseconds since the Unix epoch.
+///
+/// 'T' and 'Z' may be lowercase. The fraction may have any number of digits; digits past the
+/// ninth are truncated. A leap second (`:60`) counts as the first second of the next minute.
+/// Timestamps outside the range of `i64` nanoseconds (1677 to 2262) are an error.
+///
+/// ```
+/// use fast_atoi::timestamp::parse_rfc3339;
+///
+/// assert_eq!(parse_rfc3339(b"1970-01-01T00:00:01.5Z"), Ok(1_500_000_000));
+/// assert_eq!(
+///     parse_rfc3339(b"1996-12-19T16:39:57-08:00"),
+///     Ok(851_042_397_000_000_000)
+/// );
+/// assert!(parse_rfc3339(b"2023-02-29T00:00:00Z").is_err());
+/// ```
+#[inline]
+pub fn parse_rfc3339(s: &[u8]) -> Result<i64, ()> {
+    if s.len() < 20 {
+        return error::<i64>();
+    }
+    let seps = u128::from_le_bytes(s[3..19].try_into().unwrap());
+    let mut err = (seps & SEP_MASK != SEP) as u64;
+
+    let year = parse_4(&mut &s[0..], &mut err);
+    let month = parse_2(&mut &s[5..], &mut err);
+    let day = parse_2(&mut &s[8..], &mut err);
+    let hour = parse_2(&mut &s[11..], &mut err);
+    let minute = parse_2(&mut &s[14..], &mut err);
+    let second = parse_2(&mut &s[17..], &mut err);
+
+    let mut rest = &s[19..];
+    let mut nanos = 0;
+    if rest[0] == b'.' {
+        let n = count_digits(&rest[1..]);
+        nanos = parse_nanos(&rest[1..1 + n], &mut err);
+        rest = &rest[1 + n..];
+    }
+
+    let offset = match rest {
+        [b'Z' | b'z'] => 0,
+        [sign @ (b'+' | b'-'), _, _, b':', _, _] => {
+            let oh = parse_2(&mut &rest[1..], &mut err);
+            let om = parse_2(&mut &rest[4..], &mut err);
+            err |= (oh > 23 || om > 59) as u64;
+            let offset = (oh * 3600 + om * 60) as i64;
+            match sign {
+                b'-' => -offset,
+                _ => offset,
+            }
+        }
+        _ => return error::<i64>(),
+    };
+
+    check_date(year, month, day, &mut err);
+    check_time(hour, minute, second, &mut err);
+    if err != 0 {
+        return error::<i64>();
+    }
+
+    let days = days_from_civil(year, month, day);
+    let secs = (hour * 3600 + minute * 60 + second) as i64 - offset;
+    to_epoch_nanos(days, secs, nanos)
+}
+
+/// Converts 8 ASCII digits into four 2-digit values, one per 16-bit lane, like the first step of
+/// `parse_8`.
+#[inline(always)]
+fn pairs_8(s: &[u8], err: &mut u64) -> u64 {
+    let u = u64::from_le_bytes(s[..8].try_into().unwrap()) ^ 0x3030303030303030;
+    *err |= (u | u.wrapping_add(0x0606060606060606)) & 0xf0f0f0f0f0f0f0f0;
+    (u.wrapping_mul(10 << 8 | 1) >> 8) & 0x00ff00ff00ff00ff
+}
+
+#[inline(always)]
+fn lane(pairs: u64, i: u32) -> u64 {
+    (pairs >> (16 * i)) & 0xff
+}
+
+/// Parses the date in `YYYYMMDD` into days and checks it.
+#[inline(always)]
+fn date_8(s: &[u8], err: &mut u64) -> i64 {
+    let p = pairs_8(s, err);
+    let (year, month, day) = (lane(p, 0) * 100 + lane(p, 1), lane(p, 2), lane(p, 3));
+    check_date(year, month, day, err);
+    days_from_civil(year, month, day)
+}
+
+#[inline(always)]
+fn secs_of_day(hour: u64, minute: u64, second: u64, err: &mut u64) -> i64 {
+    check_time(hour, minute, second, err);
+    (hour * 3600 + minute * 60 + second) as i64
+}
+
+/// Parses a `YYYYMMDD` date into days since 1970-01-01.
+///
+/// ```
+/// use fast_atoi::timestamp::parse_yyyymmdd;
+///
+/// assert_eq!(parse_yyyymmdd(b"19700102"), Ok(1));
+/// assert!(parse_yyyymmdd(b"19700132").is_err());
+/// ```
+#[inline]
+pub fn parse_yyyymmdd(s: &[u8]) -> Result<i64, ()> {
+    if s.len() != 8 {
+        return error::<i64>();
+    }
+    let mut err = 0;
+    let days = date_8(s, &mut err);
+    match err {
+        0 => Ok(days),
+        _ => error::<i64>(),
+    }
+}
+
+/// Parses an `HHMMSS` time into nanoseconds since midnight. A leap second (`60`) is allowed.
+///
+/// ```
+/// use fast_atoi::timestamp::parse_hhmmss;
+///
+/// assert_eq!(parse_hhmmss(b"000102"), Ok(62_000_000_000));
+/// ```
+#[inline]
+pub fn parse_hhmmss(s: &[u8]) -> Result<i64, ()> {
+    if s.len() != 6 {
+        return error::<i64>();
+    }
+    let mut err = 0;
+    let hour = parse_2(&mut &s[0..], &mut err);
+    let minute = parse_2(&mut &s[2..], &mut err);
+    let second = parse_2(&mut &s[4..], &mut err);
+    let secs = secs_of_day(hour, minute, second, &mut err);
+    match err {
+        0 => Ok(secs * NANOS_PER_SEC),
+        _ => error::<i64>(),
+    }
+}
+
+/// Parses an `HHMMSSmmm` time into nanoseconds since midnight. A leap second (`60`) is allowed.
+///
+/// ```
+/// use fast_atoi::timestamp::parse_hhmmssmmm;
+///
+/// assert_eq!(parse_hhmmssmmm(b"000102003"), Ok(62_003_000_000));
+/// ```
+#[inline]
+pub fn parse_hhmmssmmm(s: &[u8]) -> Result<i64, ()> {
+    if s.len() != 9 {
+        return error::<i64>();
+    }
+    let mut err = 0;
+    let p = pairs_8(s, &mut err);
+    let millis = fold_1(&mut &s[8..], &mut err, lane(p, 3));
+    let secs = secs_of_day(lane(p, 0), lane(p, 1), lane(p, 2), &mut err);
+    match err {
+        0 => Ok(secs * NANOS_PER_SEC + millis as i64 * 1_000_000),
+        _ => error::<i64>(),
+    }
+}
+
+/// Parses a `YYYYMMDDHHMMSS` timestamp in UTC into nanoseconds since the Unix epoch.
+///
+/// ```
+/// use fast_atoi::timestamp::parse_yyyymmddhhmmss;
+///
+/// assert_eq!(parse_yyyymmddhhmmss(b"19700101000001"), Ok(1_000_000_000));
+/// ```
+#[inline]
+pub fn parse_yyyymmddhhmmss(s: &[u8]) -> Result<i64, ()> {
+    if s.len() != 14 {
+        return error::<i64>();
+    }
+    let mut err = 0;
+    let days = date_8(s, &mut err);
+    // "DDHHMMSS": the day was already checked, the rest is the time of day.
+    let p = pairs_8(&s[6..], &mut err);
+    let secs = secs_of_day(lane(p, 1), lane(p, 2), lane(p, 3), &mut err);
+    match err {
+        0 => to_epoch_nanos(days, secs, 0),
+        _ => error::<i64>(),
+    }
+}
+
+// The separators of "-HH:MM:S" from byte 8 to byte 15 of "YYYYMMDD-HH:MM:SS", checked as one `u64`.
+const FIX_SEP_MASK: u64 = u64::from_le_bytes([0xff, 0, 0, 0xff, 0, 0, 0xff, 0]);
+const FIX_SEP: u64 = u64::from_le_bytes([b'-', 0, 0, b':', 0, 0, b':', 0]);
+
+/// Parses a FIX `UTCTimestamp` of the form `YYYYMMDD-HH:MM:SS[.sss]` into nanoseconds since the
+/// Unix epoch.
+///
+/// The fraction may have any number of digits (FIX uses 3, 6 or 9); digits past the ninth are
+/// truncated. A leap second (`60`) is allowed.
+///
+/// ```
+/// use fast_atoi::timestamp::parse_fix_timestamp;
+///
+/// assert_eq!(
+///     parse_fix_timestamp(b"19700101-00:00:01.250"),
+///     Ok(1_250_000_000)
+/// );
+/// ```
+#[inline]
+pub fn parse_fix_timestamp(s: &[u8]) -> Result<i64, ()> {
+    if s.len() < 17 {
+        return error::<i64>();
+    }
+    let seps = u64::from_le_bytes(s[8..16].try_into().unwrap());
+    let mut err = (seps & FIX_SEP_MASK != FIX_SEP) as u64;
+    let days = date_8(s, &mut err);
+    let hour = parse_2(&mut &s[9..], &mut err);
+    let minute = parse_2(&mut &s[12..], &mut err);
+    let second = parse_2(&mut &s[15..], &mut err);
+    let secs = secs_of_day(hour, minute, second, &mut err);
+    let nanos = match &s[17..] {
+        [] => 0,
+        [b'.', frac @ ..] => parse_nanos(frac, &mut err),
+        _ => return error::<i64>(),
+    };
+    match err {
+        0 => to_epoch_nanos(days, secs, nanos),
+        _ => error::<i64>(),
+    }
+}
+
+/// Packs a 3 letter name into a word for comparison.
+const fn word(s: &[u8]) -> u32 {
+    s[0] as u32 | (s[1] as u32) << 8 | (s[2] as u32) << 16
+}
+
+/// Sets bit 5 of each byte, which lowercases ASCII letters and never turns a non-letter into one.
+const FOLD: u32 = 0x202020;
+
+const MONTHS: [u32; 12] = [
+    word(b"Jan"),
+    word(b"Feb"),
+    word(b"Mar"),
+    word(b"Apr"),
+    word(b"May"),
+    word(b"Jun"),
+    word(b"Jul"),
+    word(b"Aug"),
+    word(b"Sep"),
+    word(b"Oct"),
+    word(b"Nov"),
+    word(b"Dec"),
+];
+
+/// Indexed by days since the epoch modulo 7. 1970-01-01 was a Thursday.
+const WEEKDAYS: [u32; 7] = [
+    word(b"Thu"),
+    word(b"Fri"),
+    word(b"Sat"),
+    word(b"Sun"),
+    word(b"Mon"),
+    word(b"Tue"),
+    word(b"Wed"),
+];
+
+const LONG_WEEKDAYS: [&[u8]; 7] = [
+    b"Thursday",
+    b"Friday",
+    b"Saturday",
+    b"Sunday",
+    b"Monday",
+    b"Tuesday",
+    b"Wednesday",
+];
+
+/// Returns the month number of a 3 letter month name, setting `err` if there is none.
+#[inline]
+fn month_name(s: &[u8], fold: u32, err: &mut u64) -> u64 {
+    let w = word(s) | fold;
+    match MONTHS.iter().position(|&m| m | fold == w) {
+        Some(i) => i as u64 + 1,
+        None => {
+            *err |= 1;
+            1
+        }
+    }
+}
+
+/// Sets `err` unless the 3 letter weekday name is the weekday of `days`.
+#[inline]
+fn check_weekday(s: &[u8], fold: u32, days: i64, err: &mut u64) {
+    let expected = WEEKDAYS[days.rem_euclid(7) as usize];
+    *err |= (word(s) | fold != expected | fold) as u64;
+}
+
+/// Validates a date and time of day, and combines them into seconds since the Unix epoch.
+/// Returns the days since the epoch and the seconds.
+#[inline]
+fn epoch_secs(date: (u64, u64, u64), time: (u64, u64, u64), err: &mut u64) -> (i64, i64) {
+    let (year, month, day) = date;
+    check_date(year, month, day, err);
+    let secs = secs_of_day(time.0, time.1, time.2, err);
+    let days = days_from_civil(year, month, day);
+    (days, days * SECS_PER_DAY + secs)
+}
+
+/// Parses `HH:MM:SS`, leaving the separators to the caller.
+#[inline(always)]
+fn hh_mm_ss(s: &[u8], err: &mut u64) -> (u64, u64, u64) {
+    let hour = parse_2(&mut &s[0..], err);
+    let minute = parse_2(&mut &s[3..], err);
+    let second = parse_2(&mut &s[6..], err);
+    (hour, minute, second)
+}
+
+// "__, 06 Nov 1994 0": the separators in the first 16 bytes of an IMF-fixdate.
+const IMF_MASK: u128 =
+    u128::from_le_bytes([0, 0, 0, 0xff, 0xff, 0, 0, 0xff, 0, 0, 0, 0xff, 0, 0, 0, 0]);
+const IMF: u128 = u128::from_le_bytes([0, 0, 0, b',', b' ', 0, 0, b' ', 0, 0, 0, b' ', 0, 0, 0, 0]);
+
+// "_94 08:49:37 GMT": the separators in the last 16 bytes of an IMF-fixdate or RFC 850 date.
+const TIME_GMT_MASK: u128 = u128::from_le_bytes([
+    0, 0, 0, 0xff, 0, 0, 0xff, 0, 0, 0xff, 0, 0, 0xff, 0xff, 0xff, 0xff,
+]);
+const TIME_GMT: u128 = u128::from_le_bytes([
+    0, 0, 0, b' ', 0, 0, b':', 0, 0, b':', 0, 0, b' ', b'G', b'M', b'T',
+]);
+
+/// Sets `err` unless the last 16 bytes of `s` are a time of day followed by " GMT".
+#[inline(always)]
+fn check_time_gmt(s: &[u8], err: &mut u64) {
+    let tail = u128::from_le_bytes(s[s.len() - 16..].try_into().unwrap());
+    *err |= (tail & TIME_GMT_MASK != TIME_GMT) as u64;
+}
+
+/// Parses an IMF-fixdate: `Sun, 06 Nov 1994 08:49:37 GMT`.
+#[inline]
+fn parse_imf_fixdate(s: &[u8]) -> Result<i64, ()> {
+    if s.len() != 29 {
+        return error::<i64>();
+    }
+    let head = u128::from_le_bytes(s[0..16].try_into().unwrap());
+    let mut err = (head & IMF_MASK != IMF) as u64;
+    check_time_gmt(s, &mut err);
+    let day = parse_2(&mut &s[5..], &mut err);
+    let month = month_name(&s[8..], 0, &mut err);
+    let year = parse_4(&mut &s[12..], &mut err);
+    let time = hh_mm_ss(&s[17..], &mut err);
+    let (days, secs) = epoch_secs((year, month, day), time, &mut err);
+    check_weekday(s, 0, days, &mut err);
+    match err {
+        0 => Ok(secs),
+        _ => error::<i64>(),
+    }
+}
+
+// " 06-Nov-": the separators in the first 8 bytes after the comma of an RFC 850 date.
+const RFC850_MASK: u64 = u64::from_le_bytes([0xff, 0, 0, 0xff, 0, 0, 0, 0xff]);
+const RFC850: u64 = u64::from_le_bytes([b' ', 0, 0, b'-', 0, 0, 0, b'-']);
+
+/// Parses an obsolete RFC 850 date: `Sunday, 06-Nov-94 08:49:37 GMT`.
+#[inline]
+fn parse_rfc850(s: &[u8]) -> Result<i64, ()> {
+    let comma = match s.iter().take(10).position(|&b| b == b',') {
+        Some(i) => i,
+        None => return error::<i64>(),
+    };
+    let (name, s) = (&s[..comma], &s[comma + 1..]);
+    if s.len() != 23 {
+        return error::<i64>();
+    }
+    let head = u64::from_le_bytes(s[0..8].try_into().unwrap());
+    let mut err = (head & RFC850_MASK != RFC850) as u64;
+    check_time_gmt(s, &mut err);
+    let day = parse_2(&mut &s[1..], &mut err);
+    let month = month_name(&s[4..], 0, &mut err);
+    // A fixed pivot rather than the rolling window of RFC 9110, see `parse_http_date`.
+    let yy = parse_2(&mut &s[8..], &mut err);
+    let year = if yy >= 70 { 1900 + yy } else { 2000 + yy };
+    let time = hh_mm_ss(&s[11..], &mut err);
+    let (days, secs) = epoch_secs((year, month, day), time, &mut err);
+    err |= (name != LONG_WEEKDAYS[days.rem_euclid(7) as usize]) as u64;
+    match err {
+        0 => Ok(secs),
+        _ => error::<i64>(),
+    }
+}
+
+// "___ ___  6 08:49:37 1994": the separators of an asctime date, from byte 0 to byte 15.
+const ASCTIME_MASK: u128 =
+    u128::from_le_bytes([0, 0, 0, 0xff, 0, 0, 0, 0xff, 0, 0, 0xff, 0, 0, 0xff, 0, 0]);
+const ASCTIME: u128 =
+    u128::from_le_bytes([0, 0, 0, b' ', 0, 0, 0, b' ', 0, 0, b' ', 0, 0, b':', 0, 0]);
+
+/// Parses an asctime date: `Sun Nov  6 08:49:37 1994`.
+#[inline]
+fn parse_asctime(s: &[u8]) -> Result<i64, ()> {
+    if s.len() != 24 {
+        return error::<i64>();
+    }
+    let head = u128::from_le_bytes(s[0..16].try_into().unwrap());
+    let mut err = (head & ASCTIME_MASK != ASCTIME || s[16] != b':' || s[19] != b' ') as u64;
+    let month = month_name(&s[4..], 0, &mut err);
+    // The day is padded with a space instead of a zero.
+    let mut dd = [s[8], s[9]];
+    if dd[0] == b' ' {
+        dd[0] = b'0';
+    }
+    let day = parse_2(&mut &dd[..], &mut err);
+    let time = hh_mm_ss(&s[11..], &mut err);
+    let year = parse_4(&mut &s[20..], &mut err);
+    let (days, secs) = epoch_secs((year, month, day), time, &mut err);
+    check_weekday(s, 0, days, &mut err);
+    match err {
+        0 => Ok(secs),
+        _ => error::<i64>(),
+    }
+}
+
+/// Parses an HTTP-date (RFC 9110) into seconds since the Unix epoch.
+///
+/// All three formats are accepted, and names are case-sensitive:
+/// - IMF-fixdate: `Sun, 06 Nov 1994 08:49:37 GMT`
+/// - obsolete RFC 850: `Sunday, 06-Nov-94 08:49:37 GMT`. Two digit years from 70 are 19xx,
+///   the others are 20xx.
+/// - obsolete asctime: `Sun Nov  6 08:49:37 1994`
+///
+/// The weekday must match the date.
+///
+/// The fixed pivot deliberately deviates from RFC 9110, which reads a two digit year that appears
+/// to be more than 50 years in the future as the past century. That rule needs the current date,
+/// and this parser has no clock. The two agree until 2020; from then on, the years from 70 to 50
+/// before the current year are read as 19xx where the RFC reads 20xx. The weekday of such a date
+/// then usually does not match, so it is rejected rather than misdated.
+///
+/// ```
+/// use fast_atoi::timestamp::parse_http_date;
+///
+/// assert_eq!(parse_http_date(b"Sun, 06 Nov 1994 08:49:37 GMT"), Ok(784111777));
+/// assert_eq!(parse_http_date(b"Sunday, 06-Nov-94 08:49:37 GMT"), Ok(784111777));
+/// assert_eq!(parse_http_date(b"Sun Nov  6 08:49:37 1994"), Ok(784111777));
+/// ```
+#[inline]
+pub fn parse_http_date(s: &[u8]) -> Result<i64, ()> {
+    match s.get(3) {
+        Some(b',') => parse_imf_fixdate(s),
+        Some(b' ') => parse_asctime(s),
+        Some(_) => parse_rfc850(s),
+        None => error::<i64>(),
+    }
+}
+
+/// Skips folding white space, returning the number of bytes skipped.
+#[inline]
+fn skip_fws(s: &mut &[u8]) -> usize {
+    let n = s
+        .iter()
+        .take_while(|&&b| matches!(b, b' ' | b'\t' | b'\r' | b'\n'))
+        .count();
+    *s = &s[n..];
+    n
+}
+
+#[inline]
+fn take_digits<'a>(s: &mut &'a [u8]) -> &'a [u8] {
+    let (digits, rest) = s.split_at(count_digits(s));
+    *s = rest;
+    digits
+}
+
+/// Returns the offset in seconds of an obsolete RFC 5322 zone name.
+#[inline]
+fn obs_zone(name: &[u8]) -> Option<i64> {
+    const ZONES: [(&[u8], i64); 10] = [
+        (b"UT", 0),
+        (b"GMT", 0),
+        (b"EST", -5),
+        (b"EDT", -4),
+        (b"CST", -6),
+        (b"CDT", -5),
+        (b"MST", -7),
+        (b"MDT", -6),
+        (b"PST", -8),
+        (b"PDT", -7),
+    ];
+    match name {
+        // Military zones were defined with the wrong sign, so RFC 5322 treats them as unknown.
+        [b] if b.is_ascii_alphabetic() && !b.eq_ignore_ascii_case(&b'J') => Some(0),
+        _ => ZONES
+            .iter()
+            .find(|(zone, _)| zone.eq_ignore_ascii_case(name))
+            .map(|&(_, hours)| hours * 3600),
+    }
+}
+
+/// Parses an RFC 5322 (RFC 2822) date-time into seconds since the Unix epoch, e.g.
+/// `Tue, 1 Jul 2003 10:52:37 +0200`.
+///
+/// Names are case-insensitive and the weekday is optional, but must match the date if present.
+/// Seconds are optional. The obsolete syntax is accepted for two and three digit years and for
+/// zone names such as `GMT` or `PST`, and a trailing comment such as `(CEST)` is ignored.
+///
+/// ```
+/// use fast_atoi::timestamp::parse_rfc2822;
+///
+/// assert_eq!(parse_rfc2822(b"Tue, 1 Jul 2003 10:52:37 +0200"), Ok(1057049557));
+/// assert_eq!(parse_rfc2822(b"1 Jul 2003 08:52:37 GMT"), Ok(1057049557));
+/// ```
+pub fn parse_rfc2822(mut s: &[u8]) -> Result<i64, ()> {
+    let mut err = 0;
+    skip_fws(&mut s);
+    let mut weekday = None;
+    if !s.is_empty() && !s[0].is_ascii_digit() {
+        if s.len() < 4 {
+            return error::<i64>();
+        }
+        weekday = Some(&s[..3]);
+        s = &s[3..];
+        skip_fws(&mut s);
+        if s.first() != Some(&b',') {
+            return error::<i64>();
+        }
+        s = &s[1..];
+        skip_fws(&mut s);
+    }
+
+    let day = match take_digits(&mut s) {
+        d @ [_] => parse_1(&mut &d[..], &mut err),
+        d @ [_, _] => parse_2(&mut &d[..], &mut err),
+        _ => return error::<i64>(),
+    };
+    if skip_fws(&mut s) == 0 || s.len() < 3 {
+        return error::<i64>();
+    }
+    let month = month_name(s, FOLD, &mut err);
+    s = &s[3..];
+    if skip_fws(&mut s) == 0 {
+        return error::<i64>();
+    }
+    let year = match take_digits(&mut s) {
+        d @ [_, _, _, _] => parse_4(&mut &d[..], &mut err),
+        d @ [_, _, _] => 1900 + parse_3(&mut &d[..], &mut err),
+        d @ [_, _] => match parse_2(&mut &d[..], &mut err) {
+            yy if yy < 50 => 2000 + yy,
+            yy => 1900 + yy,
+        },
+        _ => return error::<i64>(),
+    };
+    if skip_fws(&mut s) == 0 || s.len() < 5 || s[2] != b':' {
+        return error::<i64>();
+    }
+
+    let hour = parse_2(&mut &s[0..], &mut err);
+    let minute = parse_2(&mut &s[3..], &mut err);
+    s = &s[5..];
+    let mut second = 0;
+    if s.first() == Some(&b':') {
+        if s.len() < 3 {
+            return error::<i64>();
+        }
+        second = parse_2(&mut &s[1..], &mut err);
+        s = &s[3..];
+    }
+    if skip_fws(&mut s) == 0 {
+        return error::<i64>();
+    }
+
+    let offset = match s.first() {
+        Some(&sign @ (b'+' | b'-')) if s.len() >= 5 => {
+            let oh = parse_2(&mut &s[1..], &mut err);
+            let om = parse_2(&mut &s[3..], &mut err);
+            err |= (om > 59) as u64;
+            s = &s[5..];
+            let offset = (oh * 3600 + om * 60) as i64;
+            match sign {
+                b'-' => -offset,
+                _ => offset,
+            }
+        }
+        _ => {
+            let n = s.iter().take_while(|b| b.is_ascii_alphabetic()).count();
+            let zone = obs_zone(&s[..n]);
+            s = &s[n..];
+            match zone {
+                Some(offset) => offset,
+                None => return error::<i64>(),
+            }
+        }
+    };
+    skip_fws(&mut s);
+    if s.first() == Some(&b'(') && s.last() == Some(&b')') {
+        s = &[];
+    }
+    err |= !s.is_empty() as u64;
+
+    let (days, secs) = epoch_secs((year, month, day), (hour, minute, second), &mut err);
+    if let Some(name) = weekday {
+        check_weekday(name, FOLD, days, &mut err);
+    }
+    match err {
+        0 => Ok(secs - offset),
+        _ => error::<i64>(),
+    }
+}
@@ -0,0 +1,88 @@
+use crate::core::error;
+use crate::FromRadix10Checked;
+
+/// The code point of the digit zero of every Unicode 16.0 `Nd` run.
+/// Each run is ten consecutive code points, from zero to nine.
+const ZEROS: [u32; 76] = [
+    0x30, 0x660, 0x6F0, 0x7C0, 0x966, 0x9E6, 0xA66, 0xAE6, 0xB66, 0xBE6, 0xC66, 0xCE6, 0xD66,
+    0xDE6, 0xE50, 0xED0, 0xF20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80, 0x1A90,
+    0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0, 0xFF10,
+    0x104A0, 0x10D30, 0x10D40, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0,
+    0x11650, 0x116C0, 0x116D0, 0x116DA, 0x11730, 0x118E0, 0x11950, 0x11BF0, 0x11C50, 0x11D50,
+    0x11DA0, 0x11F50, 0x16130, 0x16A60, 0x16AC0, 0x16B50, 0x16D70, 0x1CCF0, 0x1D7CE, 0x1D7D8,
+    0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E4F0, 0x1E5F1, 0x1E950, 0x1FBF0,
+];
+
+/// Returns the zero of the `Nd` run containing `c`.
+#[inline]
+fn digit_zero(c: char) -> Option<u32> {
+    let c = c as u32;
+    let i = match ZEROS.binary_search(&c) {
+        Ok(i) => i,
+        Err(0) => return None,
+        Err(i) => i - 1,
+    };
+    match c - ZEROS[i] {
+        0..=9 => Some(ZEROS[i]),
+        _ => None,
+    }
+}
+
+#[cold]
+fn parse_non_ascii<I: FromRadix10Checked>(text: &str) -> Result<I, ()> {
+    // Room for a sign and the 39 digits of the longest type, after leading zeros are stripped.
+    let mut buf = [0u8; 40];
+    let mut len = 0;
+    let mut chars = text.chars().peekable();
+    if let Some(&c @ ('+' | '-')) = chars.peek() {
+        buf[0] = c as u8;
+        len = 1;
+        chars.next();
+    }
+    let zero = match chars.peek().copied().and_then(digit_zero) {
+        Some(zero) => zero,
+        None => return error::<I>(),
+    };
+    let mut leading = true;
+    for c in chars {
+        let d = (c as u32).wrapping_sub(zero);
+        if d > 9 || (!leading && len == buf.len()) {
+            return error::<I>();
+        }
+        if leading && d == 0 {
+            continue;
+        }
+        leading = false;
+        buf[len] = b'0' + d as u8;
+        len += 1;
+    }
+    if leading {
+        buf[len] = b'0';
+        len += 1;
+    }
+    I::from_radix_10_checked(&buf[..len])
+}
+
+/// Parses an integer whose digits may be any Unicode decimal digits (general category `Nd`), e.g.
+/// full-width "１２３" or Arabic-Indic "١٢٣".
+///
+/// All digits must come from the same run of ten, so "1٢" is an error. The optional sign must be
+/// an ASCII '+' or '-'. Otherwise the grammar is the same as [`crate::atoi`], which ASCII input
+/// goes straight to.
+///
+/// ```
+/// use fast_atoi::*;
+///
+/// assert_eq!(atoi_unicode::<u32>("１２３"), Ok(123));
+/// assert_eq!(atoi_unicode::<i32>("-١٢٣"), Ok(-123));
+/// assert_eq!(atoi_unicode::<u32>("123"), Ok(123));
+/// assert!(atoi_unicode::<u32>("1٢").is_err());
+/// ```
+#[inline]
+pub fn atoi_unicode<I: FromRadix10Checked>(text: &str) -> Result<I, ()> {
+    match I::from_radix_10_checked(text.as_bytes()) {
+        Ok(x) => Ok(x),
+        Err(()) if text.is_ascii() => error::<I>(),
+        Err(()) => parse_non_ascii(text),
+    }
+}
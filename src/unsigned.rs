@@ -43,7 +43,7 @@ fn parse_u64(s: &mut &[u8], err: &mut u64) -> u64 {
     if s.len() >= 4 {
         let lo = parse_4(s, err);
         if res >= 18446744_07370955 {
-            *err |= (res as u128 * 10000 + lo as u128 >> 64) as u64;
+            *err |= ((res as u128 * 10000 + lo as u128) >> 64) as u64;
         }
         res = res.wrapping_mul(10000);
         res = res.wrapping_add(lo);
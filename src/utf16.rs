@@ -0,0 +1,62 @@
+use crate::core::error;
+use crate::simd::dispatch_sse2;
+use crate::FromRadix10Checked;
+
+/// The most digits any supported type can hold once leading zeros are stripped.
+const MAX_DIGITS: usize = 39;
+
+const ZERO: u16 = b'0' as u16;
+const PLUS: u16 = b'+' as u16;
+const MINUS: u16 = b'-' as u16;
+
+#[inline(always)]
+fn narrow_16(src: &[u16], dst: &mut [u8]) {
+    dispatch_sse2!(narrow_16(src, dst))
+}
+
+/// Narrows `src` into `dst`. Every unit that is not a byte becomes a non-digit byte, so the
+/// narrowed text parses if and only if the original does.
+#[inline]
+fn narrow(mut src: &[u16], mut dst: &mut [u8]) {
+    while src.len() >= 16 {
+        narrow_16(src, dst);
+        src = &src[16..];
+        dst = &mut dst[16..];
+    }
+    for (d, &u) in dst.iter_mut().zip(src) {
+        *d = u.min(0xff) as u8;
+    }
+}
+
+/// Parses an integer from UTF-16 code units of the pattern:
+/// - unsigned: `r"\+?[0-9]+$"`
+/// - signed: `r"(\+|-)?[0-9]+$"`
+///
+/// The expected format is the exact same as `std::str::FromStr` on the decoded string.
+///
+/// ```
+/// use fast_atoi::*;
+///
+/// let text: Vec<u16> = "-1234".encode_utf16().collect();
+/// assert_eq!(atoi_utf16::<i32>(&text), Ok(-1234));
+/// ```
+#[inline]
+pub fn atoi_utf16<I: FromRadix10Checked>(mut text: &[u16]) -> Result<I, ()> {
+    // Room for a sign and MAX_DIGITS digits, rounded up to whole 16 unit blocks.
+    let mut buf = [0u8; 48];
+    let mut sign = 0;
+    if !text.is_empty() && (text[0] == PLUS || text[0] == MINUS) {
+        buf[0] = text[0] as u8;
+        sign = 1;
+        text = &text[1..];
+    }
+    while text.len() > 1 && text[0] == ZERO {
+        text = &text[1..];
+    }
+    if text.len() > MAX_DIGITS {
+        return error::<I>();
+    }
+    let len = sign + text.len();
+    narrow(text, &mut buf[sign..len]);
+    I::from_radix_10_checked(&buf[..len])
+}
@@ -0,0 +1,40 @@
+use crate::core::error;
+use crate::simd::dispatch_sse41;
+
+#[inline(always)]
+fn parse_uuid_simple(s: &[u8]) -> Result<u128, ()> {
+    dispatch_sse41!(parse_uuid_simple(s))
+}
+
+#[inline(always)]
+fn parse_uuid_hyphenated(s: &[u8]) -> Result<u128, ()> {
+    dispatch_sse41!(parse_uuid_hyphenated(s))
+}
+
+/// Parses a UUID into a `u128`, e.g. `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+///
+/// Hex digits may be either case. All of the common forms are accepted:
+/// - hyphenated: `67e55044-10b1-426f-9247-bb680e5fe0c8`
+/// - simple: `67e5504410b1426f9247bb680e5fe0c8`
+/// - braced: `{67e55044-10b1-426f-9247-bb680e5fe0c8}`
+/// - URN: `urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8`
+///
+/// ```
+/// use fast_atoi::parse_uuid;
+///
+/// let id = 0x67e55044_10b1_426f_9247_bb680e5fe0c8;
+/// assert_eq!(parse_uuid(b"67e55044-10b1-426f-9247-bb680e5fe0c8"), Ok(id));
+/// assert_eq!(parse_uuid(b"67E5504410B1426F9247BB680E5FE0C8"), Ok(id));
+/// assert_eq!(parse_uuid(b"{67e55044-10b1-426f-9247-bb680e5fe0c8}"), Ok(id));
+/// assert_eq!(parse_uuid(b"urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8"), Ok(id));
+/// ```
+#[inline]
+pub fn parse_uuid(s: &[u8]) -> Result<u128, ()> {
+    match s.len() {
+        36 => parse_uuid_hyphenated(s),
+        32 => parse_uuid_simple(s),
+        38 if s[0] == b'{' && s[37] == b'}' => parse_uuid_hyphenated(&s[1..37]),
+        45 if s[..9].eq_ignore_ascii_case(b"urn:uuid:") => parse_uuid_hyphenated(&s[9..]),
+        _ => error::<u128>(),
+    }
+}
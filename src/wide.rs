@@ -0,0 +1,266 @@
+use crate::biguint::{for_each_chunk, mul_add, CHUNK};
+use crate::core::{error, strip_leading_zeros};
+use crate::{FromRadix10Checked, FromRadix10CheckedNoPlus};
+use core::cmp::Ordering;
+
+/// Parses the digits `r"[0-9]+"` into `N` little-endian limbs, 16 digits at a time. `max_digits`
+/// is the number of digits of the largest value.
+#[inline]
+fn parse_limbs<const N: usize>(mut s: &[u8], max_digits: usize) -> Result<[u64; N], ()> {
+    if s.is_empty() {
+        return error::<[u64; N]>();
+    }
+    strip_leading_zeros(&mut s, max_digits);
+    if s.len() > max_digits {
+        return error::<[u64; N]>();
+    }
+    let mut limbs = [0u64; N];
+    let mut carry = 0;
+    for_each_chunk(s, |chunk| carry |= mul_add(&mut limbs, CHUNK, chunk))?;
+    match carry {
+        0 => Ok(limbs),
+        _ => error::<[u64; N]>(),
+    }
+}
+
+/// Parses a sign and digits into the `N` limbs of a two's complement integer.
+#[inline]
+fn parse_signed_limbs<const N: usize>(mut s: &[u8], max_digits: usize) -> Result<[u64; N], ()> {
+    let mut negative = false;
+    if !s.is_empty() && (s[0] == b'+' || s[0] == b'-') {
+        negative = s[0] == b'-';
+        s = &s[1..];
+    }
+    let mut limbs = parse_limbs::<N>(s, max_digits)?;
+    if limbs[N - 1] >> 63 != 0 {
+        // Only the magnitude of MIN, a lone top bit, has the top bit set and still fits.
+        let is_min = limbs[..N - 1].iter().all(|&l| l == 0) && limbs[N - 1] == 1 << 63;
+        if !(negative && is_min) {
+            return error::<[u64; N]>();
+        }
+    }
+    if negative {
+        // Two's complement: invert and add one.
+        let mut carry = true;
+        for limb in &mut limbs {
+            let (x, c) = (!*limb).overflowing_add(carry as u64);
+            *limb = x;
+            carry = c;
+        }
+    }
+    Ok(limbs)
+}
+
+macro_rules! impl_wide {
+    (
+        $unsigned:ident, $signed:ident, $bits:literal, $n:literal, $max_digits:literal,
+        $unsigned_example:literal, $signed_example:literal
+    ) => {
+        #[doc = concat!("A ", $bits, "-bit unsigned integer, as little-endian `u64` limbs.")]
+        ///
+        /// This is only a container for parsed values. It parses with [`crate::atoi`] and converts
+        /// to and from its limbs and the primitive unsigned types.
+        ///
+        #[doc = $unsigned_example]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $unsigned(pub [u64; $n]);
+
+        #[doc = concat!("A ", $bits, "-bit signed integer, as the little-endian `u64` limbs of its")]
+        /// two's complement.
+        ///
+        /// This is only a container for parsed values. It parses with [`crate::atoi`] and converts
+        /// to and from its limbs and the primitive signed types.
+        ///
+        #[doc = $signed_example]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $signed(pub [u64; $n]);
+
+        impl $unsigned {
+            /// The smallest value, 0.
+            pub const MIN: $unsigned = $unsigned([0; $n]);
+            /// The largest value, with every bit set.
+            pub const MAX: $unsigned = $unsigned([u64::MAX; $n]);
+        }
+
+        impl $signed {
+            /// The smallest value, with only the sign bit set.
+            pub const MIN: $signed = {
+                let mut limbs = [0; $n];
+                limbs[$n - 1] = 1 << 63;
+                $signed(limbs)
+            };
+            /// The largest value, with every bit but the sign bit set.
+            pub const MAX: $signed = {
+                let mut limbs = [u64::MAX; $n];
+                limbs[$n - 1] = u64::MAX >> 1;
+                $signed(limbs)
+            };
+
+            #[inline]
+            fn is_negative(&self) -> bool {
+                self.0[$n - 1] >> 63 != 0
+            }
+        }
+
+        impl Ord for $unsigned {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.iter().rev().cmp(other.0.iter().rev())
+            }
+        }
+
+        impl PartialOrd for $unsigned {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $signed {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                match (self.is_negative(), other.is_negative()) {
+                    (false, true) => Ordering::Greater,
+                    (true, false) => Ordering::Less,
+                    // Two's complement orders the same as unsigned within each sign.
+                    _ => $unsigned(self.0).cmp(&$unsigned(other.0)),
+                }
+            }
+        }
+
+        impl PartialOrd for $signed {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl From<u128> for $unsigned {
+            #[inline]
+            fn from(x: u128) -> Self {
+                let mut limbs = [0; $n];
+                limbs[0] = x as u64;
+                limbs[1] = (x >> 64) as u64;
+                $unsigned(limbs)
+            }
+        }
+
+        impl From<i128> for $signed {
+            #[inline]
+            fn from(x: i128) -> Self {
+                let mut limbs = [(x >> 127) as u64; $n];
+                limbs[0] = x as u64;
+                limbs[1] = (x >> 64) as u64;
+                $signed(limbs)
+            }
+        }
+
+        impl_wide!(@from $unsigned, u128, u8, u16, u32, u64);
+        impl_wide!(@from $signed, i128, i8, i16, i32, i64);
+
+        impl From<[u64; $n]> for $unsigned {
+            #[inline]
+            fn from(limbs: [u64; $n]) -> Self {
+                $unsigned(limbs)
+            }
+        }
+
+        impl From<$unsigned> for [u64; $n] {
+            #[inline]
+            fn from(x: $unsigned) -> Self {
+                x.0
+            }
+        }
+
+        impl From<[u64; $n]> for $signed {
+            #[inline]
+            fn from(limbs: [u64; $n]) -> Self {
+                $signed(limbs)
+            }
+        }
+
+        impl From<$signed> for [u64; $n] {
+            #[inline]
+            fn from(x: $signed) -> Self {
+                x.0
+            }
+        }
+
+        impl FromRadix10CheckedNoPlus for $unsigned {
+            #[inline]
+            fn from_radix_10_checked_no_plus(s: &[u8]) -> Result<Self, ()> {
+                parse_limbs(s, $max_digits).map($unsigned)
+            }
+        }
+
+        impl FromRadix10Checked for $unsigned {
+            #[inline]
+            fn from_radix_10_checked(mut s: &[u8]) -> Result<Self, ()> {
+                if !s.is_empty() && s[0] == b'+' {
+                    s = &s[1..];
+                }
+                Self::from_radix_10_checked_no_plus(s)
+            }
+        }
+
+        impl FromRadix10Checked for $signed {
+            #[inline]
+            fn from_radix_10_checked(s: &[u8]) -> Result<Self, ()> {
+                parse_signed_limbs(s, $max_digits).map($signed)
+            }
+        }
+    };
+    (@from $wide:ident, $via:ty, $($type:ty),*) => {
+        $(
+            impl From<$type> for $wide {
+                #[inline]
+                fn from(x: $type) -> Self {
+                    Self::from(x as $via)
+                }
+            }
+        )*
+    };
+}
+
+impl_wide!(
+    U256,
+    I256,
+    "256",
+    4,
+    78,
+    r#"```
+use fast_atoi::*;
+
+let x: U256 = atoi(b"340282366920938463463374607431768211456").unwrap();
+assert_eq!(x, U256([0, 0, 1, 0]));
+assert!(atoi::<U256>(&[b'9'; 78]).is_err());
+```"#,
+    r#"```
+use fast_atoi::*;
+
+let x: I256 = atoi(b"-1").unwrap();
+assert_eq!(x, I256([u64::MAX; 4]));
+assert_eq!(x, I256::from(-1i128));
+```"#
+);
+impl_wide!(
+    U512,
+    I512,
+    "512",
+    8,
+    155,
+    r#"```
+use fast_atoi::*;
+
+let x: U512 = atoi(b"340282366920938463463374607431768211456").unwrap();
+assert_eq!(x, U512([0, 0, 1, 0, 0, 0, 0, 0]));
+assert!(atoi::<U512>(&[b'9'; 155]).is_err());
+```"#,
+    r#"```
+use fast_atoi::*;
+
+let x: I512 = atoi(b"-1").unwrap();
+assert_eq!(x, I512([u64::MAX; 8]));
+assert_eq!(x, I512::from(-1i128));
+```"#
+);
@@ -0,0 +1,108 @@
+mod common;
+use common::*;
+
+use fast_atoi::*;
+
+/// A deterministic string of `len` digits with a non-zero first digit.
+fn digits(len: usize, seed: u64) -> String {
+    let mut rng = XorShift(seed | 1);
+    (0..len)
+        .map(|i| {
+            let d = (rng.next_u64() % 10) as u8;
+            (b'0' + if i == 0 && d == 0 { 1 } else { d }) as char
+        })
+        .collect()
+}
+
+#[test]
+fn biguint_small() {
+    for (s, limbs) in [
+        ("0", vec![]),
+        ("+0", vec![]),
+        ("0000", vec![]),
+        ("1", vec![1]),
+        ("+0042", vec![42]),
+        ("18446744073709551615", vec![u64::MAX]),
+        ("18446744073709551616", vec![0, 1]),
+        (
+            "340282366920938463463374607431768211455",
+            vec![u64::MAX, u64::MAX],
+        ),
+        ("340282366920938463463374607431768211456", vec![0, 0, 1]),
+    ] {
+        assert_eq!(parse_biguint(s.as_bytes()), Ok(limbs.clone()), "{}", s);
+        let mut buf = [0u64; 3];
+        let len = parse_biguint_into(s.as_bytes(), &mut buf).unwrap();
+        assert_eq!(&buf[..len], &limbs[..], "{}", s);
+    }
+    for s in ["", "+", "-1", "-0", "1 ", " 1", "12a", "1.0", "++1"] {
+        assert!(parse_biguint(s.as_bytes()).is_err(), "{}", s);
+        assert!(
+            parse_biguint_into(s.as_bytes(), &mut [0; 4]).is_err(),
+            "{}",
+            s
+        );
+    }
+}
+
+#[test]
+fn biguint_matches_u128() {
+    for len in 1..=39 {
+        for seed in 0..20 {
+            let s = digits(len, seed);
+            let limbs = parse_biguint(s.as_bytes()).unwrap();
+            let mut buf = [0u64; 3];
+            let n = parse_biguint_into(s.as_bytes(), &mut buf).unwrap();
+            assert_eq!(&buf[..n], &limbs[..]);
+            if let Ok(x) = atoi::<u128>(s.as_bytes()) {
+                let expected: Vec<u64> = [x as u64, (x >> 64) as u64]
+                    .into_iter()
+                    .take(((128 - x.leading_zeros() + 63) / 64) as usize)
+                    .collect();
+                assert_eq!(limbs, expected, "{}", s);
+            }
+        }
+    }
+}
+
+#[test]
+fn biguint_long() {
+    // Lengths around the chunk, threshold and power of two boundaries.
+    for len in [
+        40, 319, 320, 511, 512, 513, 527, 528, 529, 1023, 1024, 1025, 1500, 2048, 4097, 10_000,
+    ] {
+        let s = digits(len, len as u64);
+        let limbs = parse_biguint(s.as_bytes()).unwrap();
+        assert_eq!(limbs_to_decimal(&limbs), s, "{}", len);
+        assert_ne!(limbs.last(), Some(&0));
+
+        let mut buf = vec![0u64; limbs.len()];
+        assert_eq!(parse_biguint_into(s.as_bytes(), &mut buf), Ok(limbs.len()));
+        assert_eq!(buf, limbs);
+        assert!(parse_biguint_into(s.as_bytes(), &mut buf[1..]).is_err());
+
+        // Leading zeros and powers of ten.
+        let padded = format!("000{}", s);
+        assert_eq!(parse_biguint(padded.as_bytes()), Ok(limbs));
+        let power = format!("1{}", "0".repeat(len));
+        assert_eq!(
+            limbs_to_decimal(&parse_biguint(power.as_bytes()).unwrap()),
+            power
+        );
+        let nines = "9".repeat(len);
+        assert_eq!(
+            limbs_to_decimal(&parse_biguint(nines.as_bytes()).unwrap()),
+            nines
+        );
+    }
+}
+
+#[test]
+fn biguint_very_long() {
+    // Against the quadratic conversion, which shares none of the multiplication code.
+    let s = digits(50_000, 7);
+    let limbs = parse_biguint(s.as_bytes()).unwrap();
+    let mut buf = vec![0u64; limbs.len()];
+    assert_eq!(parse_biguint_into(s.as_bytes(), &mut buf), Ok(limbs.len()));
+    assert_eq!(buf, limbs);
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "bytes")]
+
+mod common;
+use common::*;
+
+use bytes::Buf;
+use fast_atoi::*;
+
+/// Splits `input` into a chain of three chunks at every pair of offsets and parses it.
+fn assert_get_atoi_correct<I>(input: &[u8])
+where
+    I: FromRadix10Checked + std::str::FromStr + PartialEq + std::fmt::Debug,
+{
+    let sign = matches!(input.first(), Some(b'+' | b'-')) as usize;
+    let len = sign
+        + input[sign..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+    let expected = correct_parse::<I>(&input[..len]).ok_or(());
+    for i in 0..=input.len() {
+        for j in i..=input.len() {
+            let mut buf = input[..i].chain(&input[i..j]).chain(&input[j..]);
+            assert_eq!(get_atoi::<I>(&mut buf), expected, "{:?} {} {}", input, i, j);
+            assert_eq!(
+                buf.remaining(),
+                input.len() - len,
+                "{:?} {} {}",
+                input,
+                i,
+                j
+            );
+        }
+    }
+}
+
+#[test]
+fn get_atoi_chunks() {
+    for input in [
+        "",
+        "-",
+        "+",
+        "0",
+        "42",
+        "-42 ",
+        "+42\r\n",
+        "x42",
+        "255,256",
+        "-128;",
+        "0000000000000000000000000000000000000000000000042",
+        "18446744073709551615 ",
+        "18446744073709551616",
+        "-9223372036854775808",
+        "-9223372036854775809 ",
+        "340282366920938463463374607431768211455",
+        "340282366920938463463374607431768211456",
+    ] {
+        let input = input.as_bytes();
+        assert_get_atoi_correct::<u8>(input);
+        assert_get_atoi_correct::<u64>(input);
+        assert_get_atoi_correct::<i64>(input);
+        assert_get_atoi_correct::<u128>(input);
+        assert_get_atoi_correct::<i128>(input);
+    }
+}
+
+#[test]
+fn get_atoi_sequence() {
+    let mut buf = bytes::Bytes::from_static(b"1,-2,3").chain(&b"45,6"[..]);
+    let mut values = Vec::new();
+    loop {
+        values.push(get_atoi::<i32>(&mut buf).unwrap());
+        if !buf.has_remaining() {
+            break;
+        }
+        assert_eq!(buf.get_u8(), b',');
+    }
+    assert_eq!(values, [1, -2, 345, 6]);
+}
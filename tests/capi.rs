@@ -0,0 +1,92 @@
+#![cfg(feature = "capi")]
+
+use fast_atoi::capi::*;
+use std::os::raw::c_char;
+use std::ptr;
+
+fn ptr(s: &str) -> *const c_char {
+    s.as_ptr() as *const c_char
+}
+
+#[test]
+fn capi_ok() {
+    unsafe {
+        let mut x = 0u64;
+        assert_eq!(fast_atoi_u64(ptr("12345"), 5, &mut x), FastAtoiStatus::Ok);
+        assert_eq!(x, 12345);
+        // Only `len` bytes are read.
+        assert_eq!(fast_atoi_u64(ptr("12345"), 3, &mut x), FastAtoiStatus::Ok);
+        assert_eq!(x, 123);
+
+        let mut x = 0i8;
+        assert_eq!(fast_atoi_i8(ptr("-128"), 4, &mut x), FastAtoiStatus::Ok);
+        assert_eq!(x, -128);
+
+        let mut x = 0u128;
+        let s = u128::MAX.to_string();
+        assert_eq!(fast_atoi_u128(ptr(&s), s.len(), &mut x), FastAtoiStatus::Ok);
+        assert_eq!(x, u128::MAX);
+
+        let mut x = 0i128;
+        let s = i128::MIN.to_string();
+        assert_eq!(fast_atoi_i128(ptr(&s), s.len(), &mut x), FastAtoiStatus::Ok);
+        assert_eq!(x, i128::MIN);
+
+        assert_eq!(
+            fast_atoi_u32(ptr("+7"), 2, ptr::null_mut()),
+            FastAtoiStatus::Ok
+        );
+    }
+}
+
+#[test]
+fn capi_errors() {
+    unsafe {
+        let mut x = 7u8;
+        for s in ["", "+", "-", "-0", "1a", " 1", "256a", "-256"] {
+            assert_eq!(
+                fast_atoi_u8(ptr(s), s.len(), &mut x),
+                FastAtoiStatus::Invalid,
+                "{}",
+                s
+            );
+        }
+        for s in ["256", "+256", "0000000000000000000000000000000000000000256"] {
+            assert_eq!(
+                fast_atoi_u8(ptr(s), s.len(), &mut x),
+                FastAtoiStatus::Overflow,
+                "{}",
+                s
+            );
+        }
+        assert_eq!(x, 7);
+
+        let mut x = 7i16;
+        for s in ["", "+", "-", "--1", "1-", "0x10"] {
+            assert_eq!(
+                fast_atoi_i16(ptr(s), s.len(), &mut x),
+                FastAtoiStatus::Invalid,
+                "{}",
+                s
+            );
+        }
+        for s in ["32768", "-32769", "+99999999999999999999999"] {
+            assert_eq!(
+                fast_atoi_i16(ptr(s), s.len(), &mut x),
+                FastAtoiStatus::Overflow,
+                "{}",
+                s
+            );
+        }
+        assert_eq!(x, 7);
+
+        assert_eq!(
+            fast_atoi_i64(ptr::null(), 0, &mut 0),
+            FastAtoiStatus::Invalid
+        );
+        assert_eq!(
+            fast_atoi_i64(ptr::null(), 1, &mut 0),
+            FastAtoiStatus::Invalid
+        );
+    }
+}
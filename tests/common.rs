@@ -1,3 +1,6 @@
+// Each test crate uses only some of the helpers.
+#![allow(dead_code)]
+
 #[cold]
 pub(crate) fn cold<T>(x: T) -> T {
     x
@@ -17,8 +20,44 @@ pub fn correct_parse<I: std::str::FromStr>(data: &[u8]) -> Option<I> {
     }
     let valid_digits = data[z..].iter().all(|&b| b.is_ascii_digit());
     if valid_digits {
-        unsafe { str::from_utf8_unchecked(data) }.parse().ok()
+        unsafe { std::str::from_utf8_unchecked(data) }.parse().ok()
     } else {
-        return None;
+        None
+    }
+}
+
+/// A xorshift64 generator, for deterministic pseudo-random test input. The seed must not be 0.
+pub struct XorShift(pub u64);
+
+impl XorShift {
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+/// Formats little-endian `u64` limbs in decimal by long division.
+pub fn limbs_to_decimal(limbs: &[u64]) -> String {
+    const E19: u64 = 10_000_000_000_000_000_000;
+    let mut limbs = limbs.to_vec();
+    let mut parts = Vec::new();
+    while !limbs.is_empty() {
+        let mut rem = 0u128;
+        for limb in limbs.iter_mut().rev() {
+            let t = (rem << 64) | *limb as u128;
+            *limb = (t / E19 as u128) as u64;
+            rem = t % E19 as u128;
+        }
+        parts.push(rem as u64);
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+    }
+    let mut s = parts.pop().map_or("0".to_string(), |p| p.to_string());
+    for p in parts.iter().rev() {
+        s.push_str(&format!("{:019}", p));
     }
+    s
 }
@@ -0,0 +1,77 @@
+use core::time::Duration;
+use fast_atoi::*;
+
+#[test]
+fn duration_units() {
+    for (s, d) in [
+        ("0", Duration::ZERO),
+        ("90", Duration::from_secs(90)),
+        ("1.5", Duration::from_millis(1500)),
+        ("7ns", Duration::from_nanos(7)),
+        ("7us", Duration::from_micros(7)),
+        ("7µs", Duration::from_micros(7)),
+        ("7μs", Duration::from_micros(7)),
+        ("250ms", Duration::from_millis(250)),
+        ("1.5s", Duration::from_millis(1500)),
+        ("2m", Duration::from_secs(120)),
+        ("2h", Duration::from_secs(7200)),
+        ("2d", Duration::from_secs(172_800)),
+        ("1h30m", Duration::from_secs(5400)),
+        ("1d2h3m4s5ms6us7ns", Duration::new(93_784, 5_006_007)),
+        ("30m1h", Duration::from_secs(5400)),
+        ("1s1s", Duration::from_secs(2)),
+        ("0.5h", Duration::from_secs(1800)),
+        ("1.000000001s", Duration::new(1, 1)),
+        ("1.0000000019s", Duration::new(1, 1)),
+        ("1.5ns", Duration::from_nanos(1)),
+        (
+            "0.333333333333333333333333d",
+            Duration::new(28_799, 999_999_999),
+        ),
+        ("0001s", Duration::from_secs(1)),
+        ("18446744073709551615s", Duration::from_secs(u64::MAX)),
+        ("18446744073709551615.999999999", Duration::MAX),
+        ("18446744073709551615999999999ns", Duration::MAX),
+    ] {
+        assert_eq!(parse_duration(s.as_bytes()), Ok(d), "{}", s);
+    }
+}
+
+#[test]
+fn duration_invalid() {
+    for s in [
+        "", "s", "-1s", "+1s", " 1s", "1s ", "1 s", "1h30", "1.s", ".5s", "1..5s", "1.5.5s",
+        "1sec", "1S", "1H", "1y", "1w", "1µ", "1e3s",
+    ] {
+        assert_eq!(
+            parse_duration(s.as_bytes()),
+            Err(DurationError::Invalid),
+            "{}",
+            s
+        );
+    }
+}
+
+#[test]
+fn duration_overflow() {
+    for s in [
+        "18446744073709551616s",
+        "18446744073709551615.9999999999999999999s1ns",
+        "18446744073709551615s1s",
+        "213503982334602d",
+        "340282366920938463463374607431768211456ns",
+        "99999999999999999999999999999999999999999d",
+    ] {
+        assert_eq!(
+            parse_duration(s.as_bytes()),
+            Err(DurationError::Overflow),
+            "{}",
+            s
+        );
+    }
+    // An invalid suffix is reported even after an overflowing number.
+    assert_eq!(
+        parse_duration(b"340282366920938463463374607431768211456x"),
+        Err(DurationError::Invalid)
+    );
+}
@@ -0,0 +1,119 @@
+mod common;
+use common::*;
+
+use fast_atoi::*;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+/// Feeds `input` in the given chunk sizes, returning the parsed value and the number of bytes of
+/// `input` consumed.
+fn feed_chunks<I: FromRadix10Checked>(input: &[u8], sizes: &[usize]) -> (Result<I, ()>, usize) {
+    let mut parser = IncrementalParser::<I>::new();
+    let mut consumed = 0;
+    let mut rest = input;
+    for &size in sizes.iter().cycle() {
+        let (chunk, tail) = rest.split_at(size.min(rest.len()));
+        match parser.feed(chunk) {
+            Progress::Done(n) => {
+                consumed += n;
+                return (parser.finish(), consumed);
+            }
+            Progress::NeedMore => consumed += chunk.len(),
+        }
+        rest = tail;
+        if rest.is_empty() {
+            break;
+        }
+    }
+    (parser.finish(), consumed)
+}
+
+fn assert_incremental_correct<I: FromRadix10Checked + FromStr + PartialEq + Debug>(input: &[u8]) {
+    // The token is the sign and digits that start the input.
+    let sign = matches!(input.first(), Some(b'+' | b'-')) as usize;
+    let len = sign
+        + input[sign..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+    let expected = (correct_parse::<I>(&input[..len]).ok_or(()), len);
+    assert_eq!(
+        feed_chunks::<I>(input, &[input.len().max(1)]),
+        expected,
+        "{:?}",
+        input
+    );
+    assert_eq!(feed_chunks::<I>(input, &[1]), expected, "{:?}", input);
+    assert_eq!(
+        feed_chunks::<I>(input, &[3, 17, 8]),
+        expected,
+        "{:?}",
+        input
+    );
+    for split in 0..=input.len() {
+        assert_eq!(
+            feed_chunks::<I>(input, &[split, input.len()]),
+            expected,
+            "{:?}",
+            input
+        );
+    }
+}
+
+fn assert_all_types(input: &str) {
+    let input = input.as_bytes();
+    assert_incremental_correct::<u8>(input);
+    assert_incremental_correct::<u16>(input);
+    assert_incremental_correct::<u32>(input);
+    assert_incremental_correct::<u64>(input);
+    assert_incremental_correct::<u128>(input);
+    assert_incremental_correct::<i8>(input);
+    assert_incremental_correct::<i16>(input);
+    assert_incremental_correct::<i32>(input);
+    assert_incremental_correct::<i64>(input);
+    assert_incremental_correct::<i128>(input);
+}
+
+#[test]
+fn incremental() {
+    for input in [
+        "",
+        "+",
+        "-",
+        "+-1",
+        "0",
+        "-0",
+        "42",
+        "-42\r\n",
+        "+42 ",
+        "255,",
+        "256",
+        "-128",
+        "-129",
+        "00000000000000000000000000000000000000000000000000000000000000000042;",
+        "-00000000000000000000000000000000000000000000000000000000000000000128",
+        "18446744073709551615",
+        "18446744073709551616",
+        "-9223372036854775808x",
+        "-9223372036854775809",
+        "340282366920938463463374607431768211455",
+        "340282366920938463463374607431768211456",
+        "3402823669209384634633746074317682114550",
+        "99999999999999999999999999999999999999999999999999999999999999999999",
+        "-170141183460469231731687303715884105728",
+        "-170141183460469231731687303715884105729",
+        "x1",
+    ] {
+        assert_all_types(input);
+    }
+}
+
+#[test]
+fn incremental_done() {
+    let mut parser = IncrementalParser::<u32>::new();
+    assert_eq!(parser.feed(b"12"), Progress::NeedMore);
+    assert_eq!(parser.digits(), 2);
+    assert_eq!(parser.feed(b" 34"), Progress::Done(0));
+    assert_eq!(parser.feed(b"34"), Progress::Done(0));
+    assert_eq!(parser.finish(), Ok(12));
+}
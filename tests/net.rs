@@ -0,0 +1,293 @@
+use fast_atoi::net::*;
+use std::net::{Ipv4Addr, SocketAddrV4};
+use std::str::FromStr;
+
+fn std_ipv4(s: &[u8]) -> Option<[u8; 4]> {
+    let s = std::str::from_utf8(s).ok()?;
+    Ipv4Addr::from_str(s).ok().map(|ip| ip.octets())
+}
+
+#[test]
+fn ipv4_valid() {
+    assert_eq!(parse_ipv4(b"0.0.0.0"), Ok([0, 0, 0, 0]));
+    assert_eq!(parse_ipv4(b"255.255.255.255"), Ok([255; 4]));
+    assert_eq!(parse_ipv4(b"1.22.133.4"), Ok([1, 22, 133, 4]));
+    assert_eq!(parse_ipv4(b"127.0.0.1"), Ok([127, 0, 0, 1]));
+    assert_eq!(
+        parse_ipv4_addr(b"192.168.100.200"),
+        Ok(Ipv4Addr::new(192, 168, 100, 200))
+    );
+}
+
+#[test]
+fn ipv4_invalid() {
+    for s in [
+        &b""[..],
+        b"1.2.3",
+        b"1.2.3.",
+        b".1.2.3",
+        b"1..2.3",
+        b"1.2.3.4.",
+        b"1.2.3.4.5",
+        b"01.2.3.4",
+        b"1.2.3.00",
+        b"256.0.0.0",
+        b"0.0.0.300",
+        b"999.999.999.999",
+        b"1.2.3.4 ",
+        b" 1.2.3.4",
+        b"1.2.3.-4",
+        b"1.2.3.+4",
+        b"1.2.3.4a",
+        b"1.2.3.1000",
+        b"1000.2.3.4",
+        b"1.2.3.4:80",
+        b"0000.0.0.0",
+        b"1.2.3.4\0",
+    ] {
+        assert!(parse_ipv4(s).is_err(), "{:?}", std::str::from_utf8(s));
+        assert_eq!(std_ipv4(s), None, "{:?}", std::str::from_utf8(s));
+    }
+}
+
+#[test]
+fn ipv4_every_octet_layout() {
+    let octets = [0u8, 7, 10, 99, 100, 255];
+    for &a in &octets {
+        for &b in &octets {
+            for &c in &octets {
+                for &d in &octets {
+                    let s = format!("{}.{}.{}.{}", a, b, c, d);
+                    assert_eq!(parse_ipv4(s.as_bytes()), Ok([a, b, c, d]), "{}", s);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn ipv4_exhaustive_octet() {
+    for x in 0..1000 {
+        for s in [
+            format!("{}.1.1.1", x),
+            format!("1.{}.1.1", x),
+            format!("1.1.{}.1", x),
+            format!("1.1.1.{}", x),
+            format!("1.1.1.{:03}", x),
+            format!("1.1.{:02}.1", x),
+        ] {
+            assert_eq!(
+                parse_ipv4(s.as_bytes()).ok(),
+                std_ipv4(s.as_bytes()),
+                "{}",
+                s
+            );
+        }
+    }
+}
+
+#[test]
+fn socket_addr_v4() {
+    assert_eq!(parse_ipv4_port(b"1.2.3.4:0"), Ok(([1, 2, 3, 4], 0)));
+    assert_eq!(parse_ipv4_port(b"1.2.3.4:65535"), Ok(([1, 2, 3, 4], 65535)));
+    assert_eq!(parse_ipv4_port(b"1.2.3.4:00080"), Ok(([1, 2, 3, 4], 80)));
+    assert_eq!(
+        parse_socket_addr_v4(b"10.0.0.1:8080"),
+        Ok(SocketAddrV4::new(Ipv4Addr::new(10, 0, 0, 1), 8080))
+    );
+    for s in [
+        "1.2.3.4",
+        "1.2.3.4:",
+        "1.2.3.4:65536",
+        "1.2.3.4:+80",
+        "1.2.3.4:-80",
+        "1.2.3.4: 80",
+        "1.2.3.4:80:",
+        "1.2.3:80",
+        ":80",
+        "1.2.3.04:80",
+    ] {
+        assert!(parse_ipv4_port(s.as_bytes()).is_err(), "{}", s);
+        assert!(SocketAddrV4::from_str(s).is_err(), "{}", s);
+    }
+}
+
+fn std_ipv6(s: &str) -> Option<[u16; 8]> {
+    std::net::Ipv6Addr::from_str(s).ok().map(|ip| ip.segments())
+}
+
+#[test]
+fn ipv6_valid() {
+    for s in [
+        "::",
+        "::1",
+        "1::",
+        "1:2:3:4:5:6:7:8",
+        "ffff:FFFF:fFfF:0:00:000:0000:1",
+        "2001:db8::8a2e:370:7334",
+        "2001:db8:0:0:1::1",
+        "1::8",
+        "1:2:3:4:5:6:7::",
+        "::2:3:4:5:6:7:8",
+        "::1.2.3.4",
+        "::ffff:192.0.2.1",
+        "1:2:3:4:5:6:255.255.255.255",
+        "1::6:1.2.3.4",
+        "1:2:3:4:5::1.2.3.4",
+    ] {
+        assert_eq!(parse_ipv6(s.as_bytes()).ok(), std_ipv6(s), "{}", s);
+        assert!(std_ipv6(s).is_some(), "{}", s);
+    }
+    assert_eq!(
+        parse_ipv6_addr(b"2001:db8::8a2e:370:7334"),
+        Ok("2001:db8::8a2e:370:7334".parse().unwrap())
+    );
+}
+
+#[test]
+fn ipv6_invalid() {
+    for s in [
+        "",
+        ":",
+        ":::",
+        "1",
+        "1:2:3:4:5:6:7",
+        "1:2:3:4:5:6:7:8:9",
+        "1:2:3:4:5:6:7:8::",
+        "::1:2:3:4:5:6:7:8",
+        "1::2::3",
+        ":1::",
+        "1:::2",
+        "12345::",
+        "g::",
+        "1:2:3:4:5:6:7:1.2.3.4",
+        "1.2.3.4::",
+        "1.2.3.4",
+        "::1.2.3",
+        "::1.2.3.04",
+        "::1.2.3.256",
+        "::1.2.3.4:1",
+        "::1a.2.3.4",
+        "1:2:3:4:5:6::1.2.3.4",
+        "1::2:3:4:5:6:7:8",
+        " ::",
+        ":: ",
+        "::+1",
+        "[::1]",
+    ] {
+        assert!(parse_ipv6(s.as_bytes()).is_err(), "{}", s);
+        assert_eq!(std_ipv6(s), None, "{}", s);
+    }
+}
+
+#[test]
+fn ipv6_every_compression() {
+    let groups = ["0", "1", "ab", "F00", "0fff", "ffff", "1.2.3.4"];
+    for len in 0..=8 {
+        for gap in 0..=len {
+            for g in &groups {
+                let parts: Vec<&str> = (0..len).map(|_| *g).collect();
+                let full = parts.join(":");
+                let compressed = format!("{}::{}", parts[..gap].join(":"), parts[gap..].join(":"));
+                for s in [full, compressed] {
+                    assert_eq!(parse_ipv6(s.as_bytes()).ok(), std_ipv6(&s), "{}", s);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn mac() {
+    let mac = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab];
+    for s in [
+        "01:23:45:67:89:ab",
+        "01:23:45:67:89:AB",
+        "01-23-45-67-89-ab",
+        "0123.4567.89ab",
+        "0123.4567.89AB",
+    ] {
+        assert_eq!(parse_mac(s.as_bytes()), Ok(mac), "{}", s);
+    }
+    assert_eq!(parse_mac(b"ff:ff:ff:ff:ff:ff"), Ok([0xff; 6]));
+    assert_eq!(parse_mac(b"0000.0000.0000"), Ok([0; 6]));
+    for s in [
+        "",
+        "01:23:45:67:89",
+        "01:23:45:67:89:ab:",
+        "01:23:45:67:89:a",
+        "01:23:45:67:89:abc",
+        "01-23:45:67:89:ab",
+        "01:23:45:67:89-ab",
+        "01.23.45.67.89.ab",
+        "01 23 45 67 89 ab",
+        "0123:4567:89ab",
+        "0123.4567-89ab",
+        "01:23:45:67:89:ag",
+        "0g:23:45:67:89:ab",
+        "0123.4567.89ag",
+        "012345.6789ab",
+        "0123456789ab",
+        "01:23:45:67:89:a\u{e9}",
+    ] {
+        assert!(parse_mac(s.as_bytes()).is_err(), "{}", s);
+    }
+}
+
+#[test]
+fn mac_every_byte() {
+    for b in 0..=255u8 {
+        let s = format!(
+            "{:02x}:{:02X}:{:02x}:{:02X}:{:02x}:{:02X}",
+            b, b, 0, b, 0xff, b
+        );
+        assert_eq!(parse_mac(s.as_bytes()), Ok([b, b, 0, b, 0xff, b]), "{}", s);
+        for i in 0..s.len() {
+            let mut bytes = s.clone().into_bytes();
+            bytes[i] = b;
+            let expected = std::str::from_utf8(&bytes).ok().and_then(|t| {
+                let hex: Vec<u8> = t
+                    .split(':')
+                    .map(|p| u8::from_str_radix(p, 16).ok())
+                    .collect::<Option<_>>()?;
+                let ok = t
+                    .split(':')
+                    .all(|p| p.len() == 2 && p.bytes().all(|b| b.is_ascii_hexdigit()));
+                if ok && hex.len() == 6 {
+                    Some(hex)
+                } else {
+                    None
+                }
+            });
+            assert_eq!(
+                parse_mac(&bytes).ok().map(|m| m.to_vec()),
+                expected,
+                "{:?}",
+                bytes
+            );
+        }
+    }
+}
+
+#[test]
+fn eui64() {
+    let eui = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef];
+    for s in [
+        "01:23:45:67:89:ab:cd:ef",
+        "01-23-45-67-89-AB-CD-EF",
+        "0123.4567.89ab.cdef",
+    ] {
+        assert_eq!(parse_eui64(s.as_bytes()), Ok(eui), "{}", s);
+    }
+    for s in [
+        "01:23:45:67:89:ab",
+        "01:23:45:67:89:ab:cd:e",
+        "01:23:45:67:89:ab:cd-ef",
+        "01-23-45-67-89-ab-cd:ef",
+        "0123.4567.89ab:cdef",
+        "0123.4567.89ab.cdeg",
+        "01:23:45:67:89:ab:cd:ef:",
+    ] {
+        assert!(parse_eui64(s.as_bytes()).is_err(), "{}", s);
+    }
+}
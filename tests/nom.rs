@@ -0,0 +1,104 @@
+#![cfg(feature = "nom")]
+
+use nom::error::{Error, ErrorKind};
+use nom::IResult;
+
+type BytesResult<'a, O> = IResult<&'a [u8], O, Error<&'a [u8]>>;
+
+/// Checks a combinator against nom's own on `&[u8]` and `&str` input.
+macro_rules! assert_same_as_nom {
+    ($input:expr, $($module:ident::$name:ident),*) => {
+        $(
+            let input: &str = $input;
+            let ours: BytesResult<$name> = fast_atoi::nom::$module::$name(input.as_bytes());
+            let theirs: BytesResult<$name> = nom::character::$module::$name(input.as_bytes());
+            assert_eq!(ours, theirs, "{}::{} {:?}", stringify!($module), stringify!($name), input);
+            let ours: IResult<&str, $name> = fast_atoi::nom::$module::$name(input);
+            let theirs: IResult<&str, $name> = nom::character::$module::$name(input);
+            assert_eq!(ours, theirs, "{}::{} {:?}", stringify!($module), stringify!($name), input);
+        )*
+    };
+}
+
+const INPUTS: [&str; 25] = [
+    "",
+    "+",
+    "-",
+    "x",
+    "0",
+    "42",
+    "42,",
+    "-42 ",
+    "+42\r\n",
+    "--1",
+    "255;",
+    "256;",
+    "-128;",
+    "-129;",
+    "000000000000000000000000000000000000000000000000000042 ",
+    "18446744073709551615",
+    "18446744073709551615 ",
+    "18446744073709551616 ",
+    "-9223372036854775808 ",
+    "-9223372036854775809 ",
+    "9223372036854775808 ",
+    "340282366920938463463374607431768211455 ",
+    "340282366920938463463374607431768211456 ",
+    "-170141183460469231731687303715884105728 ",
+    "-170141183460469231731687303715884105729 ",
+];
+
+#[test]
+fn nom_complete() {
+    for input in INPUTS {
+        assert_same_as_nom!(
+            input,
+            complete::u8,
+            complete::u16,
+            complete::u32,
+            complete::u64,
+            complete::u128,
+            complete::i8,
+            complete::i16,
+            complete::i32,
+            complete::i64,
+            complete::i128
+        );
+    }
+}
+
+#[test]
+fn nom_streaming() {
+    for input in INPUTS {
+        assert_same_as_nom!(
+            input,
+            streaming::u8,
+            streaming::u16,
+            streaming::u32,
+            streaming::u64,
+            streaming::u128,
+            streaming::i8,
+            streaming::i16,
+            streaming::i32,
+            streaming::i64,
+            streaming::i128
+        );
+    }
+}
+
+#[test]
+fn nom_combined() {
+    use nom::bytes::complete::tag;
+    use nom::sequence::{separated_pair, terminated};
+
+    let res: BytesResult<(i64, u16)> = terminated(
+        separated_pair(fast_atoi::nom::i64, tag(","), fast_atoi::nom::u16),
+        tag(";"),
+    )(b"-12,345;6".as_ref());
+    assert_eq!(res, Ok((&b"6"[..], (-12, 345))));
+    let res: BytesResult<u8> = fast_atoi::nom::u8(b"256".as_ref());
+    assert_eq!(
+        res,
+        Err(nom::Err::Error(Error::new(&b"256"[..], ErrorKind::Digit)))
+    );
+}
@@ -2,16 +2,49 @@ use proptest::prelude::*;
 mod common;
 use common::*;
 use fast_atoi::*;
+use std::str::FromStr;
 
-fn is_valid_int<I: FromRadix10Checked, V: itoa::Integer>(x: V) -> bool {
+fn is_valid_int<I: FromRadix10Checked + FromStr + PartialEq, V: itoa::Integer>(x: V) -> bool {
     let mut buffer = itoa::Buffer::new();
     let s = buffer.format(x);
     is_valid::<I>(s.as_bytes())
 }
 
-fn is_valid<I: FromRadix10Checked>(s: &[u8]) -> bool {
-    let ours = atoi::<u64>(s).ok();
-    let std = correct_parse(s);
+fn round_trips<I: ToRadix10 + FromRadix10Checked + itoa::Integer + PartialEq + Copy>(x: I) -> bool {
+    let mut buf = [0u8; 40];
+    let mut expected = itoa::Buffer::new();
+    let s = x.to_radix_10(&mut buf);
+    s == expected.format(x).as_bytes() && atoi::<I>(s).ok() == Some(x)
+}
+
+fn is_valid_utf16<I: FromRadix10Checked + FromStr + PartialEq>(s: &str) -> bool {
+    let text: Vec<u16> = s.encode_utf16().collect();
+    let ours = atoi_utf16::<I>(&text).ok();
+    let std = correct_parse::<I>(s.as_bytes());
+    ours == std
+}
+
+fn is_valid_ipv4(s: &str) -> bool {
+    let ours = fast_atoi::net::parse_ipv4(s.as_bytes()).ok();
+    let std = std::net::Ipv4Addr::from_str(s).ok().map(|ip| ip.octets());
+    ours == std
+}
+
+fn is_valid_socket_addr_v4(s: &str) -> bool {
+    let ours = fast_atoi::net::parse_socket_addr_v4(s.as_bytes()).ok();
+    let std = std::net::SocketAddrV4::from_str(s).ok();
+    ours == std
+}
+
+fn is_valid_ipv6(s: &str) -> bool {
+    let ours = fast_atoi::net::parse_ipv6(s.as_bytes()).ok();
+    let std = std::net::Ipv6Addr::from_str(s).ok().map(|ip| ip.segments());
+    ours == std
+}
+
+fn is_valid<I: FromRadix10Checked + FromStr + PartialEq>(s: &[u8]) -> bool {
+    let ours = atoi::<I>(s).ok();
+    let std = correct_parse::<I>(s);
     ours == std
 }
 
@@ -92,6 +125,37 @@ proptest! {
         prop_assert!(is_valid_int::<i128, u128>(x));
     }
 
+    #[test]
+    fn format_round_trip(x in any::<u128>()) {
+        prop_assert!(round_trips(x));
+        prop_assert!(round_trips(x as i128));
+        prop_assert!(round_trips(x as u64));
+        prop_assert!(round_trips(x as i64));
+        prop_assert!(round_trips(x as u32));
+        prop_assert!(round_trips(x as i32));
+        prop_assert!(round_trips((x >> (x as u32 & 127)) as u64));
+        prop_assert!(round_trips(x >> (x as u32 & 127)));
+    }
+
+    #[test]
+    fn infer(x in any::<i128>(), shift in 0..128u32) {
+        let x = x >> shift;
+        let s = x.to_string();
+        let kind = infer_int_type(s.as_bytes());
+        prop_assert_eq!(kind, match x {
+            0..=0xff => Some(IntKind::U8),
+            0x100..=0xffff => Some(IntKind::U16),
+            0x1_0000..=0xffff_ffff => Some(IntKind::U32),
+            0x1_0000_0000..=0xffff_ffff_ffff_ffff => Some(IntKind::U64),
+            0x1_0000_0000_0000_0000.. => Some(IntKind::U128),
+            -0x80..=-1 => Some(IntKind::I8),
+            -0x8000..=-0x81 => Some(IntKind::I16),
+            -0x8000_0000..=-0x8001 => Some(IntKind::I32),
+            -0x8000_0000_0000_0000..=-0x8000_0001 => Some(IntKind::I64),
+            _ => Some(IntKind::I128),
+        });
+    }
+
     #[test]
     fn arb_string(s in any::<String>()) {
         prop_assert!(is_valid::<u8>(s.as_bytes()));
@@ -121,4 +185,45 @@ proptest! {
         prop_assert!(is_valid::<i64>(&s));
         prop_assert!(is_valid::<i128>(&s));
     }
+
+    #[test]
+    fn arb_string_utf16(s in any::<String>()) {
+        prop_assert!(is_valid_utf16::<u8>(&s));
+        prop_assert!(is_valid_utf16::<u16>(&s));
+        prop_assert!(is_valid_utf16::<u32>(&s));
+        prop_assert!(is_valid_utf16::<u64>(&s));
+        prop_assert!(is_valid_utf16::<u128>(&s));
+
+        prop_assert!(is_valid_utf16::<i8>(&s));
+        prop_assert!(is_valid_utf16::<i16>(&s));
+        prop_assert!(is_valid_utf16::<i32>(&s));
+        prop_assert!(is_valid_utf16::<i64>(&s));
+        prop_assert!(is_valid_utf16::<i128>(&s));
+    }
+
+    #[test]
+    fn ipv4(s in "[0-9.]{0,16}") {
+        prop_assert!(is_valid_ipv4(&s));
+    }
+
+    #[test]
+    fn ipv4_octets(a in 0..1000u32, b in 0..1000u32, c in 0..300u32, d in 0..300u32, z in 0..4usize) {
+        let s = format!("{}.{}.{}.{:0z$}", a, b, c, d, z = z);
+        prop_assert!(is_valid_ipv4(&s));
+    }
+
+    #[test]
+    fn socket_addr_v4(s in "[0-9]{1,3}\\.[0-9]{1,3}\\.[0-9]{1,3}\\.[0-9]{1,3}:[0-9]{0,7}") {
+        prop_assert!(is_valid_socket_addr_v4(&s));
+    }
+
+    #[test]
+    fn ipv6(s in "[0-9a-fA-F:.]{0,45}") {
+        prop_assert!(is_valid_ipv6(&s));
+    }
+
+    #[test]
+    fn ipv6_groups(s in "(:|::)?([0-9a-fA-F]{1,5}(:|::)){0,8}([0-9a-fA-F]{0,5}|[0-9]{1,3}\\.[0-9]{1,3}\\.[0-9]{1,3}\\.[0-9]{1,3})") {
+        prop_assert!(is_valid_ipv6(&s));
+    }
 }
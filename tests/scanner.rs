@@ -0,0 +1,69 @@
+use fast_atoi::Scanner;
+use std::io::{BufReader, ErrorKind};
+
+/// Scans `input` through buffers of every small capacity, so that tokens split at every offset.
+fn scan_all<T: fast_atoi::FromRadix10Checked>(input: &[u8]) -> Vec<Vec<Result<T, ErrorKind>>> {
+    (1..=40)
+        .chain([64, 8192])
+        .map(|capacity| {
+            let mut scanner = Scanner::new(BufReader::with_capacity(capacity, input));
+            std::iter::from_fn(|| scanner.next::<T>())
+                .map(|r| r.map_err(|e| e.kind()))
+                .collect()
+        })
+        .collect()
+}
+
+fn expected<T: std::str::FromStr>(input: &str) -> Vec<Result<T, ErrorKind>> {
+    input
+        .split_ascii_whitespace()
+        .map(|s| s.parse::<T>().map_err(|_| ErrorKind::InvalidData))
+        .collect()
+}
+
+#[test]
+fn scanner_tokens() {
+    let input = "  1 -2\t+3\n\n4\r\n0000000000000000000000000000000000000000000000000000000000042 \
+                 18446744073709551615 18446744073709551616 -9223372036854775808 12a 1-2 + - \
+                 00000000000000000000000000000000000000000000000000000000000000000 \x0c99 \
+                 -0000000000000000000000000000000000000000000000000000000000000009223372036854775808 \
+                 170141183460469231731687303715884105727 1701411834604692317316873037158841057270";
+    for scanned in scan_all::<i64>(input.as_bytes()) {
+        assert_eq!(scanned, expected::<i64>(input));
+    }
+    for scanned in scan_all::<u64>(input.as_bytes()) {
+        assert_eq!(scanned, expected::<u64>(input));
+    }
+    for scanned in scan_all::<i128>(input.as_bytes()) {
+        assert_eq!(scanned, expected::<i128>(input));
+    }
+}
+
+#[test]
+fn scanner_long_runs() {
+    let mut input = String::new();
+    for i in 0..2000u64 {
+        input.push_str(&i.wrapping_mul(0x9e3779b97f4a7c15).to_string());
+        input.push_str(&" \n\t".repeat((i % 40) as usize + 1));
+    }
+    for scanned in scan_all::<u64>(input.as_bytes()) {
+        assert_eq!(scanned, expected::<u64>(&input));
+    }
+}
+
+#[test]
+fn scanner_empty() {
+    for input in ["", " ", "\n\t \r\n", &" ".repeat(100)] {
+        for scanned in scan_all::<u8>(input.as_bytes()) {
+            assert!(scanned.is_empty());
+        }
+    }
+    // Vertical tab is not whitespace, as in `u8::is_ascii_whitespace`.
+    let mut scanner = Scanner::new(&b"1\x0b2 3"[..]);
+    assert_eq!(
+        scanner.next::<u8>().unwrap().unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+    assert_eq!(scanner.next::<u8>().unwrap().unwrap(), 3);
+    assert!(scanner.next::<u8>().is_none());
+}
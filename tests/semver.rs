@@ -0,0 +1,77 @@
+use fast_atoi::*;
+
+fn semver<'a>(major: u64, minor: u64, patch: u64, pre: &'a str, build: &'a str) -> SemVer<'a> {
+    SemVer {
+        major,
+        minor,
+        patch,
+        pre: pre.as_bytes(),
+        build: build.as_bytes(),
+    }
+}
+
+#[test]
+fn semver_valid() {
+    for (s, v) in [
+        ("0.0.0", semver(0, 0, 0, "", "")),
+        ("1.2.3", semver(1, 2, 3, "", "")),
+        ("10.20.30", semver(10, 20, 30, "", "")),
+        ("1.2.3-rc.1+build.5", semver(1, 2, 3, "rc.1", "build.5")),
+        ("6.18.44-fc-v130", semver(6, 18, 44, "fc-v130", "")),
+        ("1.0.0-alpha", semver(1, 0, 0, "alpha", "")),
+        ("1.0.0-0.3.7", semver(1, 0, 0, "0.3.7", "")),
+        ("1.0.0-x.7.z.92", semver(1, 0, 0, "x.7.z.92", "")),
+        ("1.0.0-x-y-z.--", semver(1, 0, 0, "x-y-z.--", "")),
+        ("1.0.0-alpha.01a", semver(1, 0, 0, "alpha.01a", "")),
+        (
+            "1.0.0+20130313144700",
+            semver(1, 0, 0, "", "20130313144700"),
+        ),
+        ("1.0.0+001", semver(1, 0, 0, "", "001")),
+        (
+            "1.0.0-beta+exp.sha.5114f85",
+            semver(1, 0, 0, "beta", "exp.sha.5114f85"),
+        ),
+        (
+            "18446744073709551615.18446744073709551615.18446744073709551615",
+            semver(u64::MAX, u64::MAX, u64::MAX, "", ""),
+        ),
+    ] {
+        assert_eq!(parse_semver(s.as_bytes()), Ok(v), "{}", s);
+    }
+}
+
+#[test]
+fn semver_invalid() {
+    for s in [
+        "",
+        "1",
+        "1.2",
+        "1.2.",
+        "1.2.3.4",
+        "01.2.3",
+        "1.02.3",
+        "1.2.03",
+        "+1.2.3",
+        "-1.2.3",
+        "v1.2.3",
+        "1.2.3 ",
+        "1.2.-3",
+        "1.2.3-",
+        "1.2.3+",
+        "1.2.3-+",
+        "1.2.3-rc..1",
+        "1.2.3-rc.",
+        "1.2.3-.rc",
+        "1.2.3-rc.01",
+        "1.2.3-00",
+        "1.2.3-rc_1",
+        "1.2.3+build..5",
+        "1.2.3+build+5",
+        "1.2.3+build_5",
+        "1.2.3-ü",
+        "18446744073709551616.0.0",
+    ] {
+        assert!(parse_semver(s.as_bytes()).is_err(), "{}", s);
+    }
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "serde")]
+
+use fast_atoi::serde::StrInt;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Ids {
+    #[serde(with = "fast_atoi::serde::str")]
+    id: u64,
+    #[serde(with = "fast_atoi::serde::str")]
+    delta: i128,
+    small: StrInt<u8>,
+}
+
+#[test]
+fn serde_round_trip() {
+    let json = r#"{"id":"18446744073709551615","delta":"-170141183460469231731687303715884105728","small":"0"}"#;
+    let ids: Ids = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        ids,
+        Ids {
+            id: u64::MAX,
+            delta: i128::MIN,
+            small: StrInt(0),
+        }
+    );
+    assert_eq!(serde_json::to_string(&ids).unwrap(), json);
+
+    assert_eq!(
+        serde_json::from_str::<StrInt<i32>>(r#""+42""#).unwrap(),
+        StrInt(42)
+    );
+    assert_eq!(
+        serde_json::from_str::<StrInt<u16>>(r#""00042""#).unwrap(),
+        StrInt(42)
+    );
+    assert_eq!(serde_json::to_string(&StrInt(-42i8)).unwrap(), r#""-42""#);
+    assert_eq!(
+        serde_json::from_slice::<StrInt<u32>>(br#""42""#).unwrap(),
+        StrInt(42)
+    );
+}
+
+#[test]
+fn serde_bytes() {
+    use serde::de::value::{BytesDeserializer, Error};
+    use serde::de::IntoDeserializer;
+
+    let de: BytesDeserializer<Error> = b"1234".as_ref().into_deserializer();
+    assert_eq!(StrInt::<u16>::deserialize(de), Ok(StrInt(1234)));
+    let de: BytesDeserializer<Error> = b"12a4".as_ref().into_deserializer();
+    assert!(StrInt::<u16>::deserialize(de).is_err());
+}
+
+#[test]
+fn serde_errors() {
+    let err = serde_json::from_str::<StrInt<u8>>(r#""256""#).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        r#"invalid value: string "256", expected an integer in the range of u8 at line 1 column 5"#
+    );
+    let err = serde_json::from_str::<StrInt<u8>>(r#""-1""#).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        r#"invalid value: string "-1", expected an integer in the range of u8 at line 1 column 4"#
+    );
+    let err = serde_json::from_str::<StrInt<u8>>(r#""12a""#).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        r#"invalid value: string "12a", expected a string containing a u8 integer at line 1 column 5"#
+    );
+    for json in [r#""""#, r#""-""#, r#"" 1""#, r#""1.0""#] {
+        let err = serde_json::from_str::<StrInt<i64>>(json).unwrap_err();
+        assert!(
+            err.to_string().contains("expected a string containing"),
+            "{}",
+            err
+        );
+    }
+    // Bare numbers are not strings.
+    assert!(serde_json::from_str::<StrInt<u64>>("42").is_err());
+}
@@ -0,0 +1,76 @@
+use fast_atoi::*;
+
+#[test]
+fn size_suffixes() {
+    for (s, n) in [
+        ("0", 0u64),
+        ("10", 10),
+        ("10B", 10),
+        ("10k", 10_000),
+        ("10K", 10_000),
+        ("10kB", 10_000),
+        ("10Ki", 10 << 10),
+        ("10KiB", 10 << 10),
+        ("512M", 512_000_000),
+        ("512MiB", 512 << 20),
+        ("4G", 4_000_000_000),
+        ("4GiB", 4 << 30),
+        ("1.5TB", 1_500_000_000_000),
+        ("1.5Ti", 3 << 39),
+        ("2P", 2_000_000_000_000_000),
+        ("2PiB", 2 << 50),
+        ("15E", 15_000_000_000_000_000_000),
+        ("15Ei", 15 << 60),
+        ("0.5", 0),
+        ("1.9", 1),
+        ("0.001k", 1),
+        ("0.0009765625Ki", 1),
+        ("0.1234567890123456789123E", 123_456_789_012_345_678),
+        ("0010k", 10_000),
+        ("18446744073709551615", u64::MAX),
+        ("18446744073709551615B", u64::MAX),
+    ] {
+        assert_eq!(parse_size::<u64>(s.as_bytes()), Ok(n), "{}", s);
+    }
+    assert_eq!(parse_size::<u128>(b"1000000EiB"), Ok(1_000_000 << 60));
+    assert_eq!(parse_size::<u16>(b"64Ki"), Err(SizeError::Overflow));
+    assert_eq!(parse_size::<u16>(b"63.99Ki"), Ok(65_525));
+}
+
+#[test]
+fn size_invalid() {
+    for s in [
+        "", "B", "k", ".5k", "1.k", "1..5k", "-1k", "+1k", " 1k", "1k ", "1 k", "1m", "1g", "1kb",
+        "1KIB", "1ki", "1Bi", "1BB", "1iB", "1KK", "1Z", "1Zi", "1kk", "1e3",
+    ] {
+        assert_eq!(
+            parse_size::<u64>(s.as_bytes()),
+            Err(SizeError::Invalid),
+            "{}",
+            s
+        );
+    }
+}
+
+#[test]
+fn size_overflow() {
+    for s in [
+        "18446744073709551616",
+        "19E",
+        "16Ei",
+        "18446744073709551615.5k",
+        "340282366920938463463374607431768211456",
+    ] {
+        assert_eq!(
+            parse_size::<u64>(s.as_bytes()),
+            Err(SizeError::Overflow),
+            "{}",
+            s
+        );
+    }
+    assert_eq!(
+        parse_size::<u128>(b"340282366920938463463374607431768211455k"),
+        Err(SizeError::Overflow)
+    );
+    assert_eq!(parse_size::<u32>(b"4GiB"), Err(SizeError::Overflow));
+}
@@ -0,0 +1,361 @@
+use fast_atoi::timestamp::*;
+
+const NS: i64 = 1_000_000_000;
+
+#[test]
+fn rfc3339_valid() {
+    assert_eq!(parse_rfc3339(b"1970-01-01T00:00:00Z"), Ok(0));
+    assert_eq!(parse_rfc3339(b"1970-01-01t00:00:00z"), Ok(0));
+    assert_eq!(parse_rfc3339(b"1969-12-31T23:59:59Z"), Ok(-NS));
+    assert_eq!(parse_rfc3339(b"2000-01-01T00:00:00Z"), Ok(946684800 * NS));
+    assert_eq!(parse_rfc3339(b"2024-02-29T23:59:59Z"), Ok(1709251199 * NS));
+    assert_eq!(
+        parse_rfc3339(b"1985-04-12T23:20:50.52Z"),
+        Ok(482196050 * NS + 520_000_000)
+    );
+    assert_eq!(
+        parse_rfc3339(b"1996-12-19T16:39:57-08:00"),
+        Ok(851042397 * NS)
+    );
+    assert_eq!(
+        parse_rfc3339(b"1996-12-20T00:39:57+00:00"),
+        Ok(851042397 * NS)
+    );
+    assert_eq!(
+        parse_rfc3339(b"1996-12-20T06:09:57+05:30"),
+        Ok(851042397 * NS)
+    );
+    assert_eq!(
+        parse_rfc3339(b"2001-02-03T04:05:06.123456789Z"),
+        parse_rfc3339(b"2001-02-03T04:05:06.1234567891234Z")
+    );
+    assert_eq!(
+        parse_rfc3339(b"1990-12-31T23:59:60Z"),
+        parse_rfc3339(b"1991-01-01T00:00:00Z")
+    );
+}
+
+#[test]
+fn rfc3339_bounds() {
+    assert_eq!(
+        parse_rfc3339(b"2262-04-11T23:47:16.854775807Z"),
+        Ok(i64::MAX)
+    );
+    assert_eq!(
+        parse_rfc3339(b"1677-09-21T00:12:43.145224192Z"),
+        Ok(i64::MIN)
+    );
+    assert!(parse_rfc3339(b"2262-04-11T23:47:16.854775808Z").is_err());
+    assert!(parse_rfc3339(b"1677-09-21T00:12:43.145224191Z").is_err());
+    assert!(parse_rfc3339(b"9999-12-31T23:59:59Z").is_err());
+}
+
+#[test]
+fn rfc3339_invalid() {
+    for s in [
+        &b""[..],
+        b"1970-01-01T00:00:00",
+        b"1970-01-01 00:00:00Z",
+        b"1970/01/01T00:00:00Z",
+        b"1970-01-01T00-00:00Z",
+        b"1970-01-01T00:00-00Z",
+        b"1970-01-01T00:00:00.Z",
+        b"1970-01-01T00:00:00.1",
+        b"1970-01-01T00:00:00.1x",
+        b"1970-01-01T00:00:00+0000",
+        b"1970-01-01T00:00:00+00-00",
+        b"1970-01-01T00:00:00+24:00",
+        b"1970-01-01T00:00:00+00:60",
+        b"1970-01-01T00:00:00Zx",
+        b"197a-01-01T00:00:00Z",
+        b"1970-00-01T00:00:00Z",
+        b"1970-13-01T00:00:00Z",
+        b"1970-01-00T00:00:00Z",
+        b"1970-01-32T00:00:00Z",
+        b"1970-04-31T00:00:00Z",
+        b"1900-02-29T00:00:00Z",
+        b"1970-01-01T24:00:00Z",
+        b"1970-01-01T00:60:00Z",
+        b"1970-01-01T00:00:61Z",
+    ] {
+        assert!(parse_rfc3339(s).is_err(), "{:?}", std::str::from_utf8(s));
+    }
+    assert!(parse_rfc3339(b"2000-02-29T00:00:00Z").is_ok());
+}
+
+#[test]
+fn rfc3339_every_day() {
+    let mut expected = parse_rfc3339(b"1700-01-01T00:00:00Z").unwrap();
+    for year in 1700..2200 {
+        for month in 1..=12 {
+            for day in 1..=31 {
+                let s = format!("{:04}-{:02}-{:02}T00:00:00Z", year, month, day);
+                match parse_rfc3339(s.as_bytes()) {
+                    Ok(ns) => {
+                        assert_eq!(ns, expected, "{}", s);
+                        expected += 86_400 * NS;
+                    }
+                    Err(()) => assert!(day > 28, "{}", s),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn compact_date() {
+    assert_eq!(parse_yyyymmdd(b"19700101"), Ok(0));
+    assert_eq!(parse_yyyymmdd(b"19691231"), Ok(-1));
+    assert_eq!(parse_yyyymmdd(b"20000301"), Ok(11017));
+    assert_eq!(parse_yyyymmdd(b"00000101"), Ok(-719528));
+    assert_eq!(parse_yyyymmdd(b"99991231"), Ok(2932896));
+    for s in [
+        &b"1970011"[..],
+        b"197001011",
+        b"1970-101",
+        b"19700001",
+        b"19701301",
+        b"19700100",
+        b"19700230",
+        b"21000229",
+        b"1970010a",
+    ] {
+        assert!(parse_yyyymmdd(s).is_err(), "{:?}", s);
+    }
+}
+
+#[test]
+fn compact_date_every_day() {
+    let mut expected = parse_yyyymmdd(b"15000101").unwrap();
+    for year in 1500..2500 {
+        for month in 1..=12 {
+            for day in 1..=31 {
+                let s = format!("{:04}{:02}{:02}", year, month, day);
+                match parse_yyyymmdd(s.as_bytes()) {
+                    Ok(days) => {
+                        assert_eq!(days, expected, "{}", s);
+                        expected += 1;
+                    }
+                    Err(()) => assert!(day > 28, "{}", s),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn compact_time() {
+    assert_eq!(parse_hhmmss(b"000000"), Ok(0));
+    assert_eq!(parse_hhmmss(b"235959"), Ok(86399 * NS));
+    assert_eq!(parse_hhmmss(b"235960"), Ok(86400 * NS));
+    assert_eq!(parse_hhmmssmmm(b"000000000"), Ok(0));
+    assert_eq!(parse_hhmmssmmm(b"123456789"), Ok(45296 * NS + 789_000_000));
+    assert_eq!(parse_hhmmssmmm(b"235959999"), Ok(86399 * NS + 999_000_000));
+    for s in [
+        &b"240000"[..],
+        b"006000",
+        b"000061",
+        b"00000",
+        b"0000000",
+        b"00:000",
+    ] {
+        assert!(parse_hhmmss(s).is_err(), "{:?}", s);
+    }
+    for s in [
+        &b"240000000"[..],
+        b"006000000",
+        b"000061000",
+        b"00000000",
+        b"0000000000",
+        b"00000000a",
+    ] {
+        assert!(parse_hhmmssmmm(s).is_err(), "{:?}", s);
+    }
+}
+
+#[test]
+fn compact_timestamp() {
+    assert_eq!(parse_yyyymmddhhmmss(b"19700101000000"), Ok(0));
+    assert_eq!(parse_yyyymmddhhmmss(b"20240229235959"), Ok(1709251199 * NS));
+    assert!(parse_yyyymmddhhmmss(b"20230229235959").is_err());
+    assert!(parse_yyyymmddhhmmss(b"20240229245959").is_err());
+    assert!(parse_yyyymmddhhmmss(b"2024022923595").is_err());
+    assert!(parse_yyyymmddhhmmss(b"99991231235959").is_err());
+}
+
+#[test]
+fn fix_timestamp() {
+    assert_eq!(parse_fix_timestamp(b"19700101-00:00:00"), Ok(0));
+    assert_eq!(
+        parse_fix_timestamp(b"20240229-23:59:59"),
+        Ok(1709251199 * NS)
+    );
+    assert_eq!(
+        parse_fix_timestamp(b"20240229-23:59:59.123"),
+        Ok(1709251199 * NS + 123_000_000)
+    );
+    assert_eq!(
+        parse_fix_timestamp(b"20240229-23:59:59.123456"),
+        Ok(1709251199 * NS + 123_456_000)
+    );
+    assert_eq!(
+        parse_fix_timestamp(b"20240229-23:59:59.123456789"),
+        Ok(1709251199 * NS + 123_456_789)
+    );
+    assert_eq!(
+        parse_fix_timestamp(b"20240229-23:59:59.123456789"),
+        parse_rfc3339(b"2024-02-29T23:59:59.123456789Z")
+    );
+    for s in [
+        &b"19700101-00:00:0"[..],
+        b"19700101T00:00:00",
+        b"19700101-00-00:00",
+        b"19700101-00:00-00",
+        b"19700101-00:00:00.",
+        b"19700101-00:00:00,000",
+        b"19700101-00:00:00.00a",
+        b"19700101-24:00:00",
+        b"19700132-00:00:00",
+    ] {
+        assert!(parse_fix_timestamp(s).is_err(), "{:?}", s);
+    }
+}
+
+#[test]
+fn http_date() {
+    for s in [
+        &b"Sun, 06 Nov 1994 08:49:37 GMT"[..],
+        b"Sunday, 06-Nov-94 08:49:37 GMT",
+        b"Sun Nov  6 08:49:37 1994",
+        b"Sun Nov 06 08:49:37 1994",
+    ] {
+        assert_eq!(parse_http_date(s), Ok(784111777), "{:?}", s);
+    }
+    assert_eq!(parse_http_date(b"Thu, 01 Jan 1970 00:00:00 GMT"), Ok(0));
+    assert_eq!(parse_http_date(b"Wed, 31 Dec 1969 23:59:59 GMT"), Ok(-1));
+    assert_eq!(
+        parse_http_date(b"Tue, 29 Feb 2000 23:59:59 GMT"),
+        Ok(951868799)
+    );
+    assert_eq!(
+        parse_http_date(b"Fri, 31 Dec 9999 23:59:59 GMT"),
+        Ok(253402300799)
+    );
+    assert_eq!(parse_http_date(b"Thursday, 01-Jan-70 00:00:00 GMT"), Ok(0));
+    assert_eq!(
+        parse_http_date(b"Tuesday, 01-Jan-69 00:00:00 GMT"),
+        Ok(3124224000)
+    );
+    assert_eq!(parse_http_date(b"Thu Jan  1 00:00:00 1970"), Ok(0));
+}
+
+#[test]
+fn http_date_rfc850_pivot() {
+    // Two digit years from 70 are 19xx and the others 20xx, whatever the current date.
+    for (s, secs) in [
+        (&b"Thursday, 01-Jan-70 00:00:00 GMT"[..], 0),
+        (b"Friday, 31-Dec-99 00:00:00 GMT", 946598400),
+        (b"Saturday, 01-Jan-00 00:00:00 GMT", 946684800),
+        (b"Tuesday, 31-Dec-69 00:00:00 GMT", 3155673600),
+    ] {
+        assert_eq!(parse_http_date(s), Ok(secs), "{:?}", s);
+    }
+    // 2070-11-06 is a Thursday, but 70 is read as 1970, when it was a Friday.
+    assert!(parse_http_date(b"Thursday, 06-Nov-70 00:00:00 GMT").is_err());
+    assert_eq!(
+        parse_http_date(b"Friday, 06-Nov-70 00:00:00 GMT"),
+        Ok(26697600)
+    );
+}
+
+#[test]
+fn http_date_invalid() {
+    for s in [
+        &b""[..],
+        b"Sun",
+        b"Mon, 06 Nov 1994 08:49:37 GMT",
+        b"sun, 06 Nov 1994 08:49:37 GMT",
+        b"Sun, 06 nov 1994 08:49:37 GMT",
+        b"Sun, 06 Nov 1994 08:49:37 UTC",
+        b"Sun, 06 Nov 1994 08:49:37 GMT ",
+        b"Sun, 6 Nov 1994 08:49:37 GMT",
+        b"Sun, 06-Nov-1994 08:49:37 GMT",
+        b"Sun, 06 Nox 1994 08:49:37 GMT",
+        b"Sun, 06 Nov 1994 24:49:37 GMT",
+        b"Sun, 31 Nov 1994 08:49:37 GMT",
+        b"Sun,  06 Nov 1994 08:49:37 GMT",
+        b"Sun, 06 Nov 1994 08.49.37 GMT",
+        b"Sun, 06 Nov 1994 08:49:37 GMX",
+        b"Sun, 06 Nov 1994 08:49:3a GMT",
+        b"Sunday, 06 Nov 94 08:49:37 GMT",
+        b"Sun, 06-Nov-94 08:49:37 GMT",
+        b"Monday, 06-Nov-94 08:49:37 GMT",
+        b"Sunday, 06-Nov-94 08:49:37",
+        b"Sunday, 06-Nov-94 08:49:37 GMT ",
+        b"Sun Nov  6 08:49:37 94",
+        b"Sun Nov 6 08:49:37 1994",
+        b"Sun Nov  6 08:49:37  1994",
+        b"Sun Nov  6 08-49:37 1994",
+        b"Sat Nov  6 08:49:37 1994",
+        b"Sun Nov   8:49:37 1994",
+    ] {
+        assert!(parse_http_date(s).is_err(), "{:?}", std::str::from_utf8(s));
+    }
+}
+
+#[test]
+fn rfc2822() {
+    for s in [
+        &b"Tue, 1 Jul 2003 10:52:37 +0200"[..],
+        b"Tue, 01 Jul 2003 10:52:37 +0200",
+        b"tue, 1 jul 2003 10:52:37 +0200",
+        b"1 Jul 2003 10:52:37 +0200",
+        b"Tue, 1 Jul 2003 10:52:37 +0200 (CEST)",
+        b"  Tue ,  1  Jul  2003  10:52:37  +0200  ",
+        b"Tue, 1 Jul 2003 08:52:37 GMT",
+        b"Tue, 1 Jul 2003 08:52:37 UT",
+        b"Tue, 1 Jul 2003 08:52:37 Z",
+        b"Tue, 1 Jul 2003 04:52:37 EDT",
+        b"Tue, 1 Jul 03 08:52:37 -0000",
+    ] {
+        assert_eq!(parse_rfc2822(s), Ok(1057049557), "{:?}", s);
+    }
+    assert_eq!(
+        parse_rfc2822(b"Fri, 21 Nov 1997 09:55:06 -0600"),
+        Ok(880127706)
+    );
+    assert_eq!(
+        parse_rfc2822(b"Thu, 13 Feb 1969 23:32:54 -0330"),
+        Ok(-27723426)
+    );
+    assert_eq!(parse_rfc2822(b"13 Feb 69 23:32 -0330"), Ok(-27723426 - 54));
+    assert_eq!(
+        parse_rfc2822(b"Thu, 13 Feb 069 23:32:54 -0330"),
+        Ok(-27723426)
+    );
+}
+
+#[test]
+fn rfc2822_invalid() {
+    for s in [
+        &b""[..],
+        b"Wed, 1 Jul 2003 10:52:37 +0200",
+        b"Tue 1 Jul 2003 10:52:37 +0200",
+        b"Tue, 123 Jul 2003 10:52:37 +0200",
+        b"Tue, 1 Jux 2003 10:52:37 +0200",
+        b"Tue, 1 Jul 20031 10:52:37 +0200",
+        b"Tue, 1 Jul 2003 10:52 :37 +0200",
+        b"Tue, 1 Jul 2003 10:52:37",
+        b"Tue, 1 Jul 2003 10:52:37 +020",
+        b"Tue, 1 Jul 2003 10:52:37 +0260",
+        b"Tue, 1 Jul 2003 10:52:37 J",
+        b"Tue, 1 Jul 2003 10:52:37 CEST",
+        b"Tue, 1 Jul 2003 10:52:37 +0200 x",
+        b"Tue, 1 Jul 2003 10:52:37+0200",
+        b"Tue, 1 Jul 2003 25:52:37 +0200",
+        b"Tue, 31 Jun 2003 10:52:37 +0200",
+        b"Tue,1Jul 2003 10:52:37 +0200",
+    ] {
+        assert!(parse_rfc2822(s).is_err(), "{:?}", std::str::from_utf8(s));
+    }
+}
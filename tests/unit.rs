@@ -54,6 +54,30 @@ mod tests {
         assert!(atoi::<i128>(b"1234a").is_err());
     }
 
+    #[test]
+    fn short_signed_invalid() {
+        assert!(atoi::<i8>(b"-+B").is_err());
+        all_short_signed::<i8>();
+        all_short_signed::<i16>();
+        all_short_signed::<i32>();
+        all_short_signed::<i64>();
+        all_short_signed::<i128>();
+    }
+
+    // One and two bytes after a sign, where invalid digits used to overflow when negated.
+    fn all_short_signed<I: FromRadix10Checked + PartialEq + Debug + FromStr>() {
+        for sign in [&b"+"[..], b"-"] {
+            for a in 0..=u8::MAX {
+                let s = [sign, &[a]].concat();
+                assert_eq!(atoi::<I>(&s).ok(), correct_parse::<I>(&s), "{:?}", s);
+                for b in 0..=u8::MAX {
+                    let s = [sign, &[a, b]].concat();
+                    assert_eq!(atoi::<I>(&s).ok(), correct_parse::<I>(&s), "{:?}", s);
+                }
+            }
+        }
+    }
+
     #[test]
     fn zeros() {
         assert_eq!(atoi::<u8>(b"0000"), Ok(0));
@@ -127,6 +151,316 @@ mod tests {
         );
     }
 
+    #[test]
+    fn options_std() {
+        for s in [
+            &b""[..],
+            b"+",
+            b"-",
+            b"0",
+            b"+7",
+            b"-7",
+            b"007",
+            b"-007",
+            b"255",
+            b"256",
+        ] {
+            assert_eq!(atoi_with::<u8>(s, &ParseOptions::STD), atoi::<u8>(s));
+            assert_eq!(atoi_with::<i16>(s, &ParseOptions::STD), atoi::<i16>(s));
+            assert_eq!(
+                atoi_with::<u64>(s, &ParseOptions::NO_PLUS),
+                atoi_no_plus::<u64>(s)
+            );
+        }
+    }
+
+    #[test]
+    fn options_plus_sign() {
+        const OPTS: ParseOptions = ParseOptions::new().plus_sign(false);
+        assert!(atoi_with::<u32>(b"+1", &OPTS).is_err());
+        assert!(atoi_with::<i32>(b"+1", &OPTS).is_err());
+        assert_eq!(atoi_with::<i32>(b"-1", &OPTS), Ok(-1));
+        assert_eq!(atoi_with::<i32>(b"1", &OPTS), Ok(1));
+    }
+
+    #[test]
+    fn options_leading_zeros() {
+        const OPTS: ParseOptions = ParseOptions::new().leading_zeros(false);
+        assert_eq!(atoi_with::<u64>(b"0", &OPTS), Ok(0));
+        assert_eq!(atoi_with::<u64>(b"10", &OPTS), Ok(10));
+        assert_eq!(atoi_with::<i64>(b"-10", &OPTS), Ok(-10));
+        assert!(atoi_with::<u64>(b"00", &OPTS).is_err());
+        assert!(atoi_with::<u64>(b"01", &OPTS).is_err());
+        assert!(atoi_with::<u64>(b"+01", &OPTS).is_err());
+        assert!(atoi_with::<i128>(b"-01", &OPTS).is_err());
+    }
+
+    #[test]
+    fn options_max_digits() {
+        const OPTS: ParseOptions = ParseOptions::new().max_digits(3);
+        assert_eq!(atoi_with::<u64>(b"999", &OPTS), Ok(999));
+        assert_eq!(atoi_with::<i64>(b"-999", &OPTS), Ok(-999));
+        assert!(atoi_with::<u64>(b"1000", &OPTS).is_err());
+        assert!(atoi_with::<u64>(b"0001", &OPTS).is_err());
+        assert!(atoi_with::<i64>(b"-1000", &OPTS).is_err());
+    }
+
+    #[test]
+    fn options_empty_is_zero() {
+        const OPTS: ParseOptions = ParseOptions::new().empty_is_zero(true);
+        assert_eq!(atoi_with::<u8>(b"", &OPTS), Ok(0));
+        assert_eq!(atoi_with::<i128>(b"", &OPTS), Ok(0));
+        assert!(atoi_with::<u8>(b"+", &OPTS).is_err());
+        assert!(atoi_with::<i8>(b"-", &OPTS).is_err());
+    }
+
+    #[test]
+    fn canonical() {
+        assert_eq!(atoi_canonical::<u8>(b"0"), Ok(0));
+        assert_eq!(atoi_canonical::<u8>(b"255"), Ok(255));
+        assert_eq!(atoi_canonical::<i8>(b"-128"), Ok(-128));
+        assert_eq!(atoi_canonical::<i64>(b"-1"), Ok(-1));
+        assert_eq!(
+            atoi_canonical::<u128>(u128::MAX.to_string().as_bytes()),
+            Ok(u128::MAX)
+        );
+        assert_eq!(
+            atoi_canonical::<i128>(i128::MIN.to_string().as_bytes()),
+            Ok(i128::MIN)
+        );
+
+        for s in [
+            &b"00"[..],
+            b"01",
+            b"007",
+            b"+1",
+            b"+0",
+            b"-0",
+            b"-00",
+            b"-01",
+            b"",
+            b"-",
+        ] {
+            assert!(atoi_canonical::<u32>(s).is_err(), "{:?}", s);
+            assert!(atoi_canonical::<i32>(s).is_err(), "{:?}", s);
+            assert!(atoi_canonical::<i128>(s).is_err(), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn options_negative_zero() {
+        const OPTS: ParseOptions = ParseOptions::new().negative_zero(false);
+        assert_eq!(atoi_with::<i32>(b"0", &OPTS), Ok(0));
+        assert_eq!(atoi_with::<i32>(b"-001", &OPTS), Ok(-1));
+        assert!(atoi_with::<i32>(b"-0", &OPTS).is_err());
+        assert!(atoi_with::<i32>(b"-000", &OPTS).is_err());
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(atoi_json::<u64>(b"0"), Ok(0));
+        assert_eq!(atoi_json::<i64>(b"-0"), Ok(0));
+        assert_eq!(atoi_json::<i8>(b"-128"), Ok(-128));
+        assert_eq!(atoi_json::<u64>(b"18446744073709551615"), Ok(u64::MAX));
+
+        for s in [
+            &b""[..],
+            b"-",
+            b"+1",
+            b"01",
+            b"-01",
+            b"1a",
+            b" 1",
+            b"1 ",
+            b"1.",
+            b"1.e5",
+            b"1e",
+            b"1e+",
+            b".5",
+            b"1.5x",
+        ] {
+            assert_eq!(atoi_json::<i32>(s), Err(JsonIntError::Invalid), "{:?}", s);
+        }
+        for s in [
+            &b"1.0"[..],
+            b"-1.5",
+            b"0.0",
+            b"1e5",
+            b"1E+5",
+            b"1e-5",
+            b"-0.5e10",
+        ] {
+            assert_eq!(
+                atoi_json::<i32>(s),
+                Err(JsonIntError::NotAnInteger),
+                "{:?}",
+                s
+            );
+        }
+        assert_eq!(atoi_json::<u8>(b"256"), Err(JsonIntError::OutOfRange));
+        assert_eq!(atoi_json::<u8>(b"-1"), Err(JsonIntError::OutOfRange));
+        assert_eq!(atoi_json::<u8>(b"-0"), Err(JsonIntError::OutOfRange));
+        assert_eq!(
+            atoi_json::<i64>(b"-9223372036854775809"),
+            Err(JsonIntError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn json_prefix() {
+        assert_eq!(atoi_json_prefix::<u32>(b"0"), Ok((0, 1)));
+        assert_eq!(atoi_json_prefix::<u32>(b"0,"), Ok((0, 1)));
+        assert_eq!(
+            atoi_json_prefix::<i32>(b"-123456789}"),
+            Ok((-123456789, 10))
+        );
+        assert_eq!(
+            atoi_json_prefix::<u64>(b"1234567890123 "),
+            Ok((1234567890123, 13))
+        );
+        assert_eq!(atoi_json_prefix::<u32>(b"01"), Err(JsonIntError::Invalid));
+        assert_eq!(atoi_json_prefix::<u32>(b"-x"), Err(JsonIntError::Invalid));
+        assert_eq!(atoi_json_prefix::<u32>(b"1.x"), Err(JsonIntError::Invalid));
+        assert_eq!(
+            atoi_json_prefix::<u32>(b"1.25,"),
+            Err(JsonIntError::NotAnInteger)
+        );
+        assert_eq!(
+            atoi_json_prefix::<u32>(b"1e9]"),
+            Err(JsonIntError::NotAnInteger)
+        );
+        assert_eq!(
+            atoi_json_prefix::<u8>(b"1000]"),
+            Err(JsonIntError::OutOfRange)
+        );
+    }
+
+    fn utf16(s: &str) -> Vec<u16> {
+        s.encode_utf16().collect()
+    }
+
+    #[test]
+    fn utf16_simple() {
+        assert_eq!(atoi_utf16::<u8>(&utf16("255")), Ok(255));
+        assert_eq!(atoi_utf16::<i8>(&utf16("-128")), Ok(-128));
+        assert_eq!(
+            atoi_utf16::<u64>(&utf16("+12345678901234567")),
+            Ok(12345678901234567)
+        );
+        assert_eq!(
+            atoi_utf16::<u128>(&utf16(&u128::MAX.to_string())),
+            Ok(u128::MAX)
+        );
+        assert_eq!(
+            atoi_utf16::<i128>(&utf16(&format!("-{:0>100}", 42))),
+            Ok(-42)
+        );
+        assert!(atoi_utf16::<u32>(&utf16("")).is_err());
+        assert!(atoi_utf16::<i32>(&utf16("-")).is_err());
+        assert!(atoi_utf16::<u32>(&utf16("12345678901234567890123456789012345678901")).is_err());
+    }
+
+    #[test]
+    fn utf16_wide_units() {
+        // Every unit that narrows onto a digit when truncated must still be rejected.
+        let mut text = utf16("1234567890123456789");
+        for i in 0..text.len() {
+            for hi in [0x0100, 0x3000, 0x7f00, 0x8000, 0xff00] {
+                let u = text[i];
+                text[i] = u | hi;
+                assert!(atoi_utf16::<u64>(&text).is_err(), "{:?}", text);
+                assert!(atoi_utf16::<i128>(&text).is_err(), "{:?}", text);
+                text[i] = u;
+            }
+        }
+        assert!(atoi_utf16::<u32>(&utf16("１２")).is_err());
+    }
+
+    #[test]
+    fn unicode_digits() {
+        assert_eq!(atoi_unicode::<u8>("２５５"), Ok(255));
+        assert!(atoi_unicode::<u8>("２５６").is_err());
+        assert_eq!(atoi_unicode::<i16>("-٣٢٧٦٨"), Ok(-32768));
+        assert_eq!(atoi_unicode::<u32>("+१२३"), Ok(123));
+        assert_eq!(atoi_unicode::<u64>("０００００００００００００００００００００００００００００００００００００００００７"), Ok(7));
+        assert_eq!(atoi_unicode::<i64>("-００"), Ok(0));
+        assert_eq!(atoi_unicode::<u64>("𝟏𝟐"), Ok(12));
+        assert_eq!(atoi_unicode::<u8>("0"), Ok(0));
+
+        assert!(atoi_unicode::<u32>("").is_err());
+        assert!(atoi_unicode::<u32>("-").is_err());
+        assert!(atoi_unicode::<u32>("-１").is_err());
+        assert!(atoi_unicode::<u32>("＋１").is_err());
+        assert!(atoi_unicode::<u32>("１2").is_err());
+        assert!(atoi_unicode::<u32>("１٢").is_err());
+        assert!(atoi_unicode::<u32>("𝟏𝟚").is_err());
+        assert!(atoi_unicode::<u32>("Ⅻ").is_err());
+        assert!(atoi_unicode::<u32>("½").is_err());
+        assert!(atoi_unicode::<u128>(
+            "１２３４５６７８９０１２３４５６７８９０１２３４５６７８９０１２３４５６７８９０"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn unicode_scripts() {
+        fn map(s: &str, zero: u32) -> String {
+            s.chars()
+                .map(|c| match c.to_digit(10) {
+                    Some(d) => char::from_u32(zero + d).unwrap(),
+                    None => c,
+                })
+                .collect()
+        }
+        for zero in [
+            0x660, 0x6F0, 0x966, 0x1040, 0xFF10, 0x104A0, 0x1D7CE, 0x1D7F6, 0x1E950, 0x1FBF0,
+        ] {
+            assert_eq!(
+                atoi_unicode::<i128>(&map(&i128::MIN.to_string(), zero)),
+                Ok(i128::MIN)
+            );
+            assert_eq!(
+                atoi_unicode::<u64>(&map(&u64::MAX.to_string(), zero)),
+                Ok(u64::MAX)
+            );
+            assert!(atoi_unicode::<u64>(&map("18446744073709551616", zero)).is_err());
+        }
+    }
+
+    #[test]
+    fn hex_blocks() {
+        for x in 0..=u16::MAX {
+            for s in [format!("{:04x}", x), format!("{:04X}", x)] {
+                let mut err = 0;
+                assert_eq!(parse_hex_4(&mut s.as_bytes(), &mut err), x as u64);
+                assert_eq!(err, 0);
+            }
+        }
+        for x in [0u32, 1, 0x89abcdef, 0xdeadbeef, u32::MAX] {
+            let s = format!("{:08X}", x);
+            let mut err = 0;
+            assert_eq!(parse_hex_8(&mut s.as_bytes(), &mut err), x as u64);
+            assert_eq!(err, 0);
+        }
+        for b1 in 0..=255u8 {
+            for b2 in 0..=255u8 {
+                let s = [b1, b2];
+                let expected = std::str::from_utf8(&s)
+                    .ok()
+                    .filter(|s| s.bytes().all(|b| b.is_ascii_hexdigit()))
+                    .map(|s| u64::from_str_radix(s, 16).unwrap());
+                let mut err = 0;
+                let x = parse_hex_2(&mut &s[..], &mut err);
+                assert_eq!((err == 0).then(|| x), expected, "{:?}", s);
+
+                let s = [b'0', b1, b'f', b2, b'A', b'9', b1, b2];
+                let mut err = 0;
+                parse_hex_8(&mut &s[..], &mut err);
+                assert_eq!(err == 0, expected.is_some(), "{:?}", s);
+            }
+        }
+    }
+
     macro_rules! all_parse_valid_num {
         ($min:literal, $max:literal, $type:ty) => {{
             let mut buf = [42u8; 64];
@@ -316,4 +650,187 @@ mod tests {
         assert_large_correct::<i128>(i128::MIN);
         assert_large_correct::<i128>(i128::MAX);
     }
+    fn assert_format_correct<I: ToRadix10 + itoa::Integer + Copy>(x: I) {
+        let mut buf = [0u8; 40];
+        let mut expected = itoa::Buffer::new();
+        let expected = expected.format(x);
+        assert_eq!(x.to_radix_10(&mut buf), expected.as_bytes(), "{}", expected);
+        assert!(expected.len() <= I::MAX_LEN);
+    }
+
+    #[test]
+    fn format() {
+        for x in 0..=u16::MAX {
+            assert_format_correct(x);
+            assert_format_correct(x as u8);
+            assert_format_correct(x as i8);
+            assert_format_correct(x as i16);
+        }
+        let mut x = 1u128;
+        while x != 0 {
+            for y in [x - 1, x, x + 1, x.wrapping_mul(10) - 1] {
+                assert_format_correct(y);
+                assert_format_correct(y as i128);
+                assert_format_correct(y as u64);
+                assert_format_correct(y as i64);
+                assert_format_correct(y as u32);
+                assert_format_correct(y as i32);
+            }
+            x = x.wrapping_mul(10);
+            if x.leading_zeros() < 4 {
+                break;
+            }
+        }
+        for x in [u128::MAX, i128::MAX as u128, i128::MIN as u128] {
+            assert_format_correct(x);
+            assert_format_correct(x as i128);
+        }
+        assert_format_correct(u64::MAX);
+        assert_format_correct(i64::MIN);
+        assert_format_correct(i32::MIN);
+        assert_format_correct(i8::MIN);
+
+        let mut buf = [0u8; 4];
+        assert_eq!(write_radix10(-128i8, &mut buf), b"-128");
+        assert_eq!(0u64.to_radix_10(&mut buf), b"0");
+    }
+
+    #[test]
+    #[should_panic]
+    fn format_short_buffer() {
+        let mut buf = [0u8; 3];
+        1234u32.to_radix_10(&mut buf);
+    }
+
+    #[test]
+    fn write_blocks() {
+        let mut buf = [0u8; 16];
+        for n in 0..100 {
+            write_2(&mut &mut buf[..], n);
+            assert_eq!(&buf[..2], format!("{:02}", n).as_bytes());
+        }
+        for n in 0..10_000 {
+            write_4(&mut &mut buf[..], n);
+            assert_eq!(&buf[..4], format!("{:04}", n).as_bytes());
+        }
+        let mut rng = XorShift(0x0123_4567_89ab_cdef);
+        for _ in 0..100_000 {
+            let n = rng.next_u64();
+            write_8(&mut &mut buf[..], n % 100_000_000);
+            assert_eq!(&buf[..8], format!("{:08}", n % 100_000_000).as_bytes());
+            write_16(&mut &mut buf[..], n % 10_000_000_000_000_000);
+            assert_eq!(
+                &buf[..],
+                format!("{:016}", n % 10_000_000_000_000_000).as_bytes()
+            );
+        }
+        for n in [0, 99_999_999, 9_999_999_999_999_999] {
+            write_16(&mut &mut buf[..], n);
+            assert_eq!(&buf[..], format!("{:016}", n).as_bytes());
+        }
+
+        let mut out = &mut buf[..];
+        write_4(&mut out, 2024);
+        write_2(&mut out, 7);
+        write_2(&mut out, 1);
+        assert_eq!(out.len(), 8);
+        assert_eq!(&buf[..8], b"20240701");
+    }
+    /// The first type in `U8, U16, ..., I128` order that parses `s`, preferring unsigned.
+    fn narrowest(s: &[u8]) -> Option<IntKind> {
+        let negative = s.first() == Some(&b'-');
+        [
+            (IntKind::U8, is_valid::<u8>(s)),
+            (IntKind::U16, is_valid::<u16>(s)),
+            (IntKind::U32, is_valid::<u32>(s)),
+            (IntKind::U64, is_valid::<u64>(s)),
+            (IntKind::U128, is_valid::<u128>(s)),
+            (IntKind::I8, negative && is_valid::<i8>(s)),
+            (IntKind::I16, negative && is_valid::<i16>(s)),
+            (IntKind::I32, negative && is_valid::<i32>(s)),
+            (IntKind::I64, negative && is_valid::<i64>(s)),
+            (IntKind::I128, is_valid::<i128>(s)),
+        ]
+        .iter()
+        .find(|(_, valid)| *valid)
+        .map(|&(kind, _)| kind)
+    }
+
+    #[test]
+    fn infer() {
+        for (s, kind) in [
+            ("0", Some(IntKind::U8)),
+            ("+0", Some(IntKind::U8)),
+            ("-0", Some(IntKind::I8)),
+            ("255", Some(IntKind::U8)),
+            ("256", Some(IntKind::U16)),
+            ("-128", Some(IntKind::I8)),
+            ("-129", Some(IntKind::I16)),
+            ("65536", Some(IntKind::U32)),
+            ("-32769", Some(IntKind::I32)),
+            ("4294967296", Some(IntKind::U64)),
+            ("-2147483649", Some(IntKind::I64)),
+            ("18446744073709551616", Some(IntKind::U128)),
+            ("-9223372036854775809", Some(IntKind::I128)),
+            (
+                "000000000000000000000000000000000000000000255",
+                Some(IntKind::U8),
+            ),
+            (
+                "000000000000000000000000000000000000000000256",
+                Some(IntKind::U16),
+            ),
+            (
+                "-00000000000000000000000000000000000000000129",
+                Some(IntKind::I16),
+            ),
+            (
+                "340282366920938463463374607431768211455",
+                Some(IntKind::U128),
+            ),
+            (
+                "-170141183460469231731687303715884105728",
+                Some(IntKind::I128),
+            ),
+            ("340282366920938463463374607431768211456", None),
+            ("-170141183460469231731687303715884105729", None),
+            ("", None),
+            ("-", None),
+            ("+", None),
+            ("-+1", None),
+            ("+-1", None),
+            ("1.0", None),
+            (" 1", None),
+        ] {
+            assert_eq!(infer_int_type(s.as_bytes()), kind, "{}", s);
+            assert_eq!(narrowest(s.as_bytes()), kind, "{}", s);
+        }
+        for x in [
+            i64::MIN as i128,
+            i32::MIN as i128,
+            i16::MIN as i128,
+            i8::MIN as i128,
+        ] {
+            for y in [x - 1, x, x + 1, -x - 2, -x - 1, -x, 2 * -x - 1, 2 * -x] {
+                let s = y.to_string();
+                assert_eq!(
+                    infer_int_type(s.as_bytes()),
+                    narrowest(s.as_bytes()),
+                    "{}",
+                    s
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn infer_negative_zero() {
+        // The unsigned parsers reject the '-', so "-0" is the one non-negative value given a
+        // signed type.
+        for s in ["-0", "-00", "-0000000000000000000000000000000000000000000"] {
+            assert_eq!(infer_int_type(s.as_bytes()), Some(IntKind::I8), "{}", s);
+            assert!(atoi::<u8>(s.as_bytes()).is_err());
+            assert_eq!(atoi::<i8>(s.as_bytes()), Ok(0));
+        }
+    }
 }
@@ -0,0 +1,94 @@
+mod common;
+use common::*;
+
+use fast_atoi::*;
+
+fn hyphenate(simple: &str) -> String {
+    format!(
+        "{}-{}-{}-{}-{}",
+        &simple[..8],
+        &simple[8..12],
+        &simple[12..16],
+        &simple[16..20],
+        &simple[20..]
+    )
+}
+
+#[test]
+fn uuid_forms() {
+    let id = 0x67e55044_10b1_426f_9247_bb680e5fe0c8;
+    for s in [
+        "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "67E55044-10B1-426F-9247-BB680E5FE0C8",
+        "67e5504410b1426f9247bb680e5fe0c8",
+        "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+        "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "URN:UUID:67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ] {
+        assert_eq!(parse_uuid(s.as_bytes()), Ok(id), "{}", s);
+    }
+    assert_eq!(parse_uuid(b"00000000-0000-0000-0000-000000000000"), Ok(0));
+    assert_eq!(
+        parse_uuid(b"ffffffff-ffff-ffff-ffff-ffffffffffff"),
+        Ok(u128::MAX)
+    );
+}
+
+#[test]
+fn uuid_values() {
+    let mut rng = XorShift(0x0123_4567_89ab_cdef);
+    for _ in 0..10_000 {
+        let x = (rng.next_u64() as u128) << 64 | rng.next_u64() as u128;
+        let simple = format!("{:032x}", x);
+        assert_eq!(parse_uuid(simple.as_bytes()), Ok(x), "{}", simple);
+        assert_eq!(
+            parse_uuid(hyphenate(&simple).as_bytes()),
+            Ok(x),
+            "{}",
+            simple
+        );
+        let upper = simple.to_uppercase();
+        assert_eq!(parse_uuid(upper.as_bytes()), Ok(x), "{}", upper);
+        assert_eq!(parse_uuid(hyphenate(&upper).as_bytes()), Ok(x), "{}", upper);
+    }
+}
+
+#[test]
+fn uuid_invalid() {
+    for s in [
+        "",
+        "67e55044-10b1-426f-9247-bb680e5fe0c",
+        "67e55044-10b1-426f-9247-bb680e5fe0c8a",
+        "67e55044_10b1-426f-9247-bb680e5fe0c8",
+        "67e5504410b1-426f-9247-bb680e5fe0c8-",
+        "67e55044-10b1-426f-9247bbb680e5fe0c8",
+        "67e55044-10b1-426f-9247-bb680e5fe0cg",
+        "67e5504410b1426f9247bb680e5fe0cg",
+        "67e5504410b1426f9247bb680e5fe0c",
+        "{67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "(67e55044-10b1-426f-9247-bb680e5fe0c8)",
+        "{67e5504410b1426f9247bb680e5fe0c8}",
+        "urn:uuid:67e5504410b1426f9247bb680e5fe0c8",
+        "urn:uid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "urn:uuid-67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ] {
+        assert!(parse_uuid(s.as_bytes()).is_err(), "{}", s);
+    }
+
+    // Every byte in every position of both main forms.
+    let simple = "67e5504410b1426f9247bb680e5fe0c8";
+    for form in [simple.to_string(), hyphenate(simple)] {
+        for i in 0..form.len() {
+            for b in 0..=255u8 {
+                let mut s = form.clone().into_bytes();
+                s[i] = b;
+                let valid = if form.as_bytes()[i] == b'-' {
+                    b == b'-'
+                } else {
+                    b.is_ascii_hexdigit()
+                };
+                assert_eq!(parse_uuid(&s).is_ok(), valid, "{:?}", s);
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+mod common;
+use common::*;
+
+use fast_atoi::*;
+
+const U256_MAX: &str =
+    "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+const I256_MAX: &str =
+    "57896044618658097711785492504343953926634992332820282019728792003956564819967";
+const I256_MIN: &str =
+    "-57896044618658097711785492504343953926634992332820282019728792003956564819968";
+
+/// The limbs of `s` through `parse_biguint`, or `None` if it does not fit in `N` limbs.
+fn reference<const N: usize>(s: &str) -> Option<[u64; N]> {
+    let limbs = parse_biguint(s.as_bytes()).ok()?;
+    if limbs.len() > N {
+        return None;
+    }
+    let mut out = [0; N];
+    out[..limbs.len()].copy_from_slice(&limbs);
+    Some(out)
+}
+
+fn negate<const N: usize>(limbs: [u64; N]) -> [u64; N] {
+    let mut out = [0; N];
+    let mut carry = true;
+    for (o, l) in out.iter_mut().zip(limbs) {
+        let (x, c) = (!l).overflowing_add(carry as u64);
+        *o = x;
+        carry = c;
+    }
+    out
+}
+
+fn reference_signed<const N: usize>(s: &str) -> Option<[u64; N]> {
+    let (negative, digits) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    if digits.starts_with('+') {
+        return None;
+    }
+    let limbs = reference::<N>(digits)?;
+    let top = limbs[N - 1] >> 63 != 0;
+    match negative {
+        false if !top => Some(limbs),
+        true if !top || negate(limbs) == limbs => Some(negate(limbs)),
+        _ => None,
+    }
+}
+
+#[test]
+fn wide_bounds() {
+    assert_eq!(atoi::<U256>(U256_MAX.as_bytes()), Ok(U256::MAX));
+    assert_eq!(atoi::<U256>(b"0"), Ok(U256::MIN));
+    assert_eq!(atoi::<I256>(I256_MAX.as_bytes()), Ok(I256::MAX));
+    assert_eq!(atoi::<I256>(I256_MIN.as_bytes()), Ok(I256::MIN));
+    assert_eq!(atoi::<I256>(b"-0"), Ok(I256::from(0)));
+    assert_eq!(atoi::<I256>(b"-1"), Ok(I256::from(-1)));
+    assert_eq!(
+        atoi::<I256>(b"-170141183460469231731687303715884105728"),
+        Ok(I256::from(i128::MIN))
+    );
+    assert_eq!(
+        atoi::<U256>(b"340282366920938463463374607431768211455"),
+        Ok(U256::from(u128::MAX))
+    );
+    assert!(I256::MIN < I256::from(-1) && I256::from(-1) < I256::from(0));
+    assert!(I256::from(0) < I256::MAX);
+    assert!(U256::from(u128::MAX) < U256([0, 0, 1, 0]) && U256([0, 0, 1, 0]) < U256::MAX);
+
+    for s in [
+        "",
+        "+",
+        "-",
+        "++1",
+        "-1",
+        "1a",
+        " 1",
+        "115792089237316195423570985008687907853269984665640564039457584007913129639936",
+        "999999999999999999999999999999999999999999999999999999999999999999999999999999",
+        "1000000000000000000000000000000000000000000000000000000000000000000000000000000",
+    ] {
+        assert!(atoi::<U256>(s.as_bytes()).is_err(), "{}", s);
+    }
+    for s in [
+        "",
+        "+",
+        "-",
+        "+-1",
+        "--1",
+        "57896044618658097711785492504343953926634992332820282019728792003956564819968",
+        "-57896044618658097711785492504343953926634992332820282019728792003956564819969",
+        U256_MAX,
+    ] {
+        assert!(atoi::<I256>(s.as_bytes()).is_err(), "{}", s);
+    }
+    assert_eq!(atoi_no_plus::<U256>(b"+1"), Err(()));
+}
+
+#[test]
+fn wide_512_bounds() {
+    let max = parse_biguint(&[b'9'; 155]).unwrap();
+    let u512_max = limbs_to_decimal(&[u64::MAX; 8]);
+    assert_eq!(atoi::<U512>(u512_max.as_bytes()), Ok(U512::MAX));
+    assert!(max.len() > 8);
+    assert!(atoi::<U512>(&[b'9'; 155]).is_err());
+    assert_eq!(
+        atoi::<U512>(&[b'9'; 154]).map(|x| x.0.to_vec()),
+        parse_biguint(&[b'9'; 154])
+    );
+
+    let mut i512_max = [u64::MAX; 8];
+    i512_max[7] >>= 1;
+    let i512_max = limbs_to_decimal(&i512_max);
+    assert_eq!(atoi::<I512>(i512_max.as_bytes()), Ok(I512::MAX));
+    let i512_min = format!("-{}", limbs_to_decimal(&I512::MIN.0));
+    assert_eq!(atoi::<I512>(i512_min.as_bytes()), Ok(I512::MIN));
+    assert!(atoi::<I512>(format!("{}0", &i512_max[..i512_max.len() - 1]).as_bytes()).is_ok());
+    assert!(atoi::<I512>(u512_max.as_bytes()).is_err());
+    assert_eq!(I512::from(-5i8), I512::from(-5i128));
+    assert_eq!(U512::from(5u8), U512::from(5u128));
+    assert!(I512::MIN < I512::from(-1) && I512::from(-1) < I512::MAX);
+}
+
+#[test]
+fn wide_matches_biguint() {
+    let mut rng = XorShift(0x0123_4567_89ab_cdef);
+    for _ in 0..20_000 {
+        let len = (rng.next_u64() % 160) as usize;
+        let mut s: String = (0..len)
+            .map(|_| (b'0' + (rng.next_u64() % 10) as u8) as char)
+            .collect();
+        if len > 0 && rng.next_u64() % 4 == 0 {
+            s.replace_range(..1, "9");
+        }
+        for sign in ["", "+", "-"] {
+            let signed = format!("{}{}", sign, s);
+            let unsigned = match sign {
+                "-" => None,
+                _ => reference(&s),
+            };
+            assert_eq!(
+                atoi::<U256>(signed.as_bytes()).ok().map(|x| x.0),
+                unsigned,
+                "{}",
+                signed
+            );
+            assert_eq!(
+                atoi::<I256>(signed.as_bytes()).ok().map(|x| x.0),
+                reference_signed(&signed),
+                "{}",
+                signed
+            );
+        }
+    }
+    let padded = format!("{}{}", "0".repeat(100), U256_MAX);
+    assert_eq!(atoi::<U256>(padded.as_bytes()), Ok(U256::MAX));
+}